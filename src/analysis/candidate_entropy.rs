@@ -0,0 +1,95 @@
+use itertools::iproduct;
+
+use crate::candidates::reduced_template;
+use crate::traits::Sudoku;
+
+/// Measures how open a sudoku still is after easy elimination, as the sum over all empty cells of the Shannon entropy
+/// of choosing uniformly among the cell's candidates, i.e. `log2(candidate count)`. A solved sudoku has an entropy of
+/// zero and every removed candidate lowers the entropy, so more constrained sudokus have a lower entropy.
+pub fn candidate_entropy(sudoku: &Sudoku) -> f32 {
+    let template = reduced_template(sudoku);
+
+    iproduct!(0..9, 0..9)
+        .map(|(row, col)| &template.cells[row][col])
+        .filter(|cell| cell.is_empty())
+        .map(|cell| cell.possible_values().len())
+        .filter(|&count| count > 0)
+        .map(|count| (count as f32).log2())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analysis::candidate_entropy::candidate_entropy;
+    use crate::Sudoku;
+
+    const EMPTY_SUDOKU: &str = "\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+    ";
+
+    const LOOSE_SUDOKU: &str = "\
+        .....3...\
+        ....5.1..\
+        8.....2..\
+        ........4\
+        .9.....3.\
+        2...1....\
+        ..6......\
+        ....7.81.\
+        .43..9...\
+    ";
+
+    const CONSTRAINED_SUDOKU: &str = "\
+        ...6.94..\
+        29..8....\
+        .6...5...\
+        .........\
+        5......72\
+        912467583\
+        3..17..9.\
+        159..2...\
+        ...9...1.\
+    ";
+
+    const SOLVED_SUDOKU: &str = "\
+        835619427\
+        294783156\
+        761245839\
+        673528941\
+        548391672\
+        912467583\
+        386174295\
+        159832764\
+        427956318\
+    ";
+
+    #[test]
+    fn candidate_entropy_is_lower_for_more_constrained_sudoku() {
+        // Given sudokus that are increasingly constrained.
+        let empty = EMPTY_SUDOKU.parse::<Sudoku>().unwrap();
+        let loose = LOOSE_SUDOKU.parse::<Sudoku>().unwrap();
+        let constrained = CONSTRAINED_SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I compute their entropy, then it should decrease as the sudokus get more constrained.
+        assert!((candidate_entropy(&empty) - 81.0 * 9f32.log2()).abs() < 1e-3);
+        assert!(candidate_entropy(&loose) < candidate_entropy(&empty));
+        assert!(candidate_entropy(&constrained) < candidate_entropy(&loose));
+    }
+
+    #[test]
+    fn candidate_entropy_is_zero_for_solved_sudoku() {
+        // Given a solved sudoku.
+        let sudoku = SOLVED_SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I compute its entropy, then it should be zero.
+        assert_eq!(candidate_entropy(&sudoku), 0.0);
+    }
+}
@@ -0,0 +1,86 @@
+use itertools::iproduct;
+
+use crate::solving::backtracking::{count_solutions, has_unique_solution};
+use crate::traits::Sudoku;
+
+/// Provides the coordinates of the clues that can be removed while keeping the solution unique, in row-major order.
+/// An empty result for a proper puzzle means that the puzzle is minimal.
+pub fn redundant_clues(sudoku: &Sudoku) -> Vec<(usize, usize)> {
+    iproduct!(0..9, 0..9)
+        .filter(|&(row, col)| sudoku.get_cells()[row][col] != 0)
+        .filter(|&(row, col)| {
+            let mut cells = *sudoku.get_cells();
+            cells[row][col] = 0;
+            has_unique_solution(&Sudoku::new(cells))
+        })
+        .collect()
+}
+
+/// Provides, for each clue in row-major order, the number of solutions the sudoku would have without that clue, capped
+/// at 3. A count of 1 means that the clue is redundant, while higher counts mean that the clue is critical.
+pub fn clue_importance(sudoku: &Sudoku) -> Vec<((usize, usize), usize)> {
+    iproduct!(0..9, 0..9)
+        .filter(|&(row, col)| sudoku.get_cells()[row][col] != 0)
+        .map(|(row, col)| {
+            let mut cells = *sudoku.get_cells();
+            cells[row][col] = 0;
+            ((row, col), count_solutions(&Sudoku::new(cells), 3))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analysis::clues::{clue_importance, redundant_clues};
+    use crate::Sudoku;
+
+    const MINIMAL_SUDOKU: &str = "\
+        .......1.\
+        4........\
+        .2.......\
+        ....5.4.7\
+        ..8...3..\
+        ..1.9....\
+        3..4..2..\
+        .5.1.....\
+        ...8.6...\
+    ";
+
+    #[test]
+    fn redundant_clues_is_empty_for_minimal_sudoku() {
+        // Given a minimal sudoku.
+        let sudoku = MINIMAL_SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I get its redundant clues, then there should be none.
+        assert_eq!(redundant_clues(&sudoku), vec![]);
+    }
+
+    #[test]
+    fn redundant_clues_finds_added_clue() {
+        // Given a minimal sudoku with an additional clue from its solution.
+        let mut cells = *MINIMAL_SUDOKU.parse::<Sudoku>().unwrap().get_cells();
+        cells[0][0] = 6;
+        let sudoku = Sudoku::new(cells);
+
+        // When I get its redundant clues, then the additional clue should be one of them.
+        assert!(redundant_clues(&sudoku).contains(&(0, 0)));
+    }
+
+    #[test]
+    fn clue_importance_distinguishes_redundant_and_critical_clues() {
+        // Given a minimal sudoku with an additional clue from its solution.
+        let mut cells = *MINIMAL_SUDOKU.parse::<Sudoku>().unwrap().get_cells();
+        cells[0][0] = 6;
+        let sudoku = Sudoku::new(cells);
+
+        // When I get the importance of its clues.
+        let importance = clue_importance(&sudoku);
+
+        // Then there should be an entry for each clue, the additional clue should be redundant and the original clues
+        // should be critical.
+        assert_eq!(importance.len(), 18);
+        assert_eq!(importance[0], ((0, 0), 1));
+        assert!(importance[1..].iter().all(|&(_, count)| count > 1));
+        assert!(importance.iter().all(|&(_, count)| count <= 3));
+    }
+}
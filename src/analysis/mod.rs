@@ -0,0 +1,4 @@
+pub mod candidate_entropy;
+pub mod clues;
+pub mod unit_completions;
+pub mod unit_pressure;
@@ -0,0 +1,151 @@
+use itertools::Itertools;
+
+use crate::traits::{Sudoku, Unit};
+
+/// Maximum number of completions returned by `unit_completions`.
+pub const MAX_UNIT_COMPLETIONS: usize = 1000;
+
+/// Provides every way in which the empty cells of a unit can be completed with its missing values, without conflicting
+/// with the values in the rest of the sudoku. Each completion lists the values of the unit's cells in the order of
+/// `Unit::cells`. Since a unit has nine cells, there are up to 9! completions, but the values of the other cells
+/// usually reduce them drastically. At most `MAX_UNIT_COMPLETIONS` completions are returned, in lexicographic order of
+/// the values of the empty cells. Returns an empty vector if the unit is not valid or cannot be completed.
+pub fn unit_completions(sudoku: &Sudoku, unit: Unit) -> Vec<[usize; 9]> {
+    if !unit.is_valid() {
+        return vec![];
+    }
+
+    let cells = unit.cells();
+    let mut values = cells.map(|(row, col)| sudoku.get_cells()[row][col]);
+    let missing_values = (1..=9).filter(|value| !values.contains(value)).collect_vec();
+
+    // For each empty cell, the missing values that do not conflict with the cells outside of the unit.
+    let empty_cells = (0..9)
+        .filter(|&i| values[i] == 0)
+        .map(|i| {
+            let (row, col) = cells[i];
+            let allowed_values = missing_values
+                .iter()
+                .copied()
+                .filter(|&value| !conflicts_outside_unit(sudoku, &cells, row, col, value))
+                .collect_vec();
+            (i, allowed_values)
+        })
+        .collect_vec();
+
+    let mut completions = vec![];
+    complete(&empty_cells, &mut values, &mut completions);
+    completions
+}
+
+/// Returns `true` if a cell outside of the unit that is in the same row, column or square as the cell at `row` and
+/// `col` contains `value`.
+fn conflicts_outside_unit(
+    sudoku: &Sudoku,
+    unit_cells: &[(usize, usize); 9],
+    row: usize,
+    col: usize,
+    value: usize,
+) -> bool {
+    Sudoku::peer_cells(row, col)
+        .filter(|cell| !unit_cells.contains(cell))
+        .any(|(x, y)| sudoku.get_cells()[x][y] == value)
+}
+
+/// Recursively assigns distinct allowed values to the remaining empty cells, collecting every full assignment until
+/// `MAX_UNIT_COMPLETIONS` assignments were collected.
+fn complete(empty_cells: &[(usize, Vec<usize>)], values: &mut [usize; 9], completions: &mut Vec<[usize; 9]>) {
+    if completions.len() >= MAX_UNIT_COMPLETIONS {
+        return;
+    }
+    let Some(((i, allowed_values), remaining_cells)) = empty_cells.split_first() else {
+        completions.push(*values);
+        return;
+    };
+
+    for &value in allowed_values {
+        if !values.contains(&value) {
+            values[*i] = value;
+            complete(remaining_cells, values, completions);
+            values[*i] = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use crate::analysis::unit_completions::{unit_completions, MAX_UNIT_COMPLETIONS};
+    use crate::traits::Unit;
+    use crate::Sudoku;
+
+    const SUDOKU: &str = "\
+        123456...\
+        .........\
+        .........\
+        ......7..\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+    ";
+
+    const COMPLETE_ROW_SUDOKU: &str = "\
+        123456789\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+    ";
+
+    #[test]
+    fn unit_completions_respects_rest_of_sudoku() {
+        // Given a sudoku whose first row misses [7, 8, 9], with a 7 in the column of the first empty cell.
+        let sudoku = SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I get the completions of the first row.
+        let completions = unit_completions(&sudoku, Unit::Row(0));
+
+        // Then only the four completions that do not place 7 in the first empty cell should be returned.
+        assert_eq!(completions.len(), 4);
+        assert_eq!(completions.iter().any(|completion| completion[6] == 7), false);
+        assert!(completions.contains(&[1, 2, 3, 4, 5, 6, 8, 7, 9]));
+    }
+
+    #[test]
+    fn unit_completions_of_complete_unit_is_the_unit_itself() {
+        // Given a sudoku with a complete first row.
+        let sudoku = COMPLETE_ROW_SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I get the completions of the first row, then only the row itself should be returned.
+        assert_eq!(unit_completions(&sudoku, Unit::Row(0)), vec![[1, 2, 3, 4, 5, 6, 7, 8, 9]]);
+    }
+
+    #[test]
+    fn unit_completions_are_capped() {
+        // Given an empty sudoku, whose rows can be completed in 9! ways.
+        let sudoku = ".".repeat(81).parse::<Sudoku>().unwrap();
+
+        // When I get the completions of the first row.
+        let completions = unit_completions(&sudoku, Unit::Row(0));
+
+        // Then only the first completions up to the cap should be returned.
+        assert_eq!(completions.len(), MAX_UNIT_COMPLETIONS);
+        assert_eq!(completions[0], [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn unit_completions_of_invalid_unit_is_empty() {
+        // Given a sudoku.
+        let sudoku = SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I get the completions of an invalid unit, then nothing should be returned.
+        assert_eq!(unit_completions(&sudoku, Unit::Row(9)).is_empty(), true);
+        assert_eq!(unit_completions(&sudoku, Unit::Square(0, 3)).is_empty(), true);
+    }
+}
@@ -0,0 +1,69 @@
+use crate::candidates::candidate_counts;
+use crate::traits::{Sudoku, Unit};
+
+/// Measures how open each unit still is, as the average number of candidates per empty cell after easy elimination.
+/// Returns the pressure of the rows, the columns and the squares, with squares indexed in row-major order. Units with
+/// a low pressure are close to being resolved, and units without empty cells have a pressure of zero.
+pub fn unit_pressure(sudoku: &Sudoku) -> ([f32; 9], [f32; 9], [f32; 9]) {
+    let counts = candidate_counts(sudoku);
+    let pressure = |unit: Unit| {
+        let empty_cells = unit.cells()
+            .into_iter()
+            .filter(|&(row, col)| sudoku.get_cells()[row][col] == 0)
+            .map(|(row, col)| counts[row][col] as f32)
+            .collect::<Vec<_>>();
+        if empty_cells.is_empty() {
+            0.0
+        } else {
+            empty_cells.iter().sum::<f32>() / empty_cells.len() as f32
+        }
+    };
+
+    (
+        std::array::from_fn(|row| pressure(Unit::Row(row))),
+        std::array::from_fn(|col| pressure(Unit::Column(col))),
+        std::array::from_fn(|square| pressure(Unit::Square(square / 3, square % 3))),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analysis::unit_pressure::unit_pressure;
+    use crate::Sudoku;
+
+    const SUDOKU: &str = "\
+        ...6.94..\
+        29..8....\
+        .6...5...\
+        .........\
+        5......72\
+        91246758.\
+        3..17..9.\
+        159..2...\
+        ...9...1.\
+    ";
+
+    #[test]
+    fn unit_pressure_is_lower_for_nearly_full_units() {
+        // Given a sudoku with a nearly full sixth row and an empty fourth row.
+        let sudoku = SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I compute the pressure of its units.
+        let (rows, columns, squares) = unit_pressure(&sudoku);
+
+        // Then the nearly full row should have a single candidate left, and a lower pressure than the empty row.
+        assert_eq!(rows[5], 1.0);
+        assert!(rows[5] < rows[3]);
+        assert!(columns.iter().all(|&pressure| pressure >= 1.0));
+        assert!(squares.iter().all(|&pressure| pressure >= 1.0));
+    }
+
+    #[test]
+    fn unit_pressure_is_zero_for_full_units() {
+        // Given a sudoku with a full row.
+        let sudoku = SUDOKU.replace("91246758.", "912467583").parse::<Sudoku>().unwrap();
+
+        // When I compute the pressure of its units, then the full row should have no pressure.
+        assert_eq!(unit_pressure(&sudoku).0[5], 0.0);
+    }
+}
@@ -31,20 +31,6 @@ fn main() {
     print_sudoku(&solved_sudoku);
 }
 
-fn test() -> [[usize; 9]; 9] {
-    [
-        [0, 0, 6, 4, 1, 5, 0, 0, 0],
-        [4, 0, 0, 0, 0, 0, 0, 0, 0],
-        [0, 8, 0, 7, 0, 6, 0, 0, 0],
-        [0, 0, 4, 0, 0, 0, 8, 1, 0],
-        [0, 3, 1, 0, 7, 0, 2, 6, 0],
-        [0, 6, 5, 0, 0, 0, 9, 0, 0],
-        [0, 0, 0, 5, 0, 9, 0, 8, 0],
-        [0, 0, 0, 0, 0, 0, 0, 0, 3],
-        [0, 0, 0, 8, 4, 2, 6, 0, 0],
-    ]
-}
-
 fn print_sudoku(sudoku: &Sudoku) {
     println!(" {}", "-".repeat(29));
     for (index, row) in sudoku.get_cells().iter().enumerate() {
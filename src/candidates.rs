@@ -0,0 +1,227 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use itertools::iproduct;
+
+use crate::solving::eliminate_possibilities_using_existing_singles::EliminatePossibilitiesUsingExistingSingles;
+use crate::solving::traits::SudokuSolvingStrategy;
+use crate::traits::{Sudoku, SudokuTemplate};
+
+/// Compares two candidate grids and provides the candidates that were removed, as `(row, column, digit)` tuples in
+/// row-major order. Candidates that are present in `after` but not in `before` are ignored.
+pub fn candidate_diff(before: &[[Vec<usize>; 9]; 9], after: &[[Vec<usize>; 9]; 9]) -> Vec<(usize, usize, usize)> {
+    iproduct!(0..9, 0..9)
+        .flat_map(|(row, col)| {
+            before[row][col]
+                .iter()
+                .filter(move |digit| !after[row][col].contains(digit))
+                .map(move |&digit| (row, col, digit))
+        })
+        .collect()
+}
+
+/// Describes why a candidate grid is not consistent with a sudoku. Cells are provided as `(row, column)` coordinates.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CandidateError {
+    /// A filled cell does not have its value as only candidate.
+    FilledCellHasOtherCandidates(usize, usize),
+    /// An empty cell has no candidates.
+    NoCandidates(usize, usize),
+    /// A candidate of an empty cell is not between 1 and 9.
+    InvalidCandidate(usize, usize, usize),
+    /// A candidate of an empty cell is already placed in a cell of the same row, column or square.
+    ContradictsPeer(usize, usize, usize),
+}
+
+impl Display for CandidateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            CandidateError::FilledCellHasOtherCandidates(row, col) =>
+                write!(f, "Filled cell ({row}, {col}) does not have its value as only candidate"),
+            CandidateError::NoCandidates(row, col) => write!(f, "Empty cell ({row}, {col}) has no candidates"),
+            CandidateError::InvalidCandidate(row, col, value) =>
+                write!(f, "Candidate {value} of cell ({row}, {col}) is not between 1 and 9"),
+            CandidateError::ContradictsPeer(row, col, value) =>
+                write!(f, "Candidate {value} of cell ({row}, {col}) is already placed in a peer"),
+        }
+    }
+}
+
+impl Error for CandidateError {}
+
+/// Checks that a candidate grid is consistent with a sudoku, i.e. that filled cells have their value as only candidate,
+/// as expected by `solve_from_candidates`, and that empty cells have at least one candidate, none of which is already
+/// placed in the same row, column or square. Cells are checked in row-major order and the first inconsistency found is
+/// returned.
+pub fn validate_candidates(sudoku: &Sudoku, candidates: &[[Vec<usize>; 9]; 9]) -> Result<(), CandidateError> {
+    let cells = sudoku.get_cells();
+
+    for (row, col) in iproduct!(0..9, 0..9) {
+        let cell_candidates = &candidates[row][col];
+        if cells[row][col] != 0 {
+            if *cell_candidates != [cells[row][col]] {
+                return Err(CandidateError::FilledCellHasOtherCandidates(row, col));
+            }
+            continue;
+        }
+
+        if cell_candidates.is_empty() {
+            return Err(CandidateError::NoCandidates(row, col));
+        }
+        for &value in cell_candidates {
+            if !(1..=9).contains(&value) {
+                return Err(CandidateError::InvalidCandidate(row, col, value));
+            }
+            let placed_in_peer = Sudoku::peer_cells(row, col).any(|(x, y)| cells[x][y] == value);
+            if placed_in_peer {
+                return Err(CandidateError::ContradictsPeer(row, col, value));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Provides the number of candidates of every cell, as computed by easy elimination. Cells that are filled in the
+/// sudoku have no candidates.
+pub fn candidate_counts(sudoku: &Sudoku) -> [[u8; 9]; 9] {
+    let template = reduced_template(sudoku);
+    let mut counts = [[0; 9]; 9];
+    for (row, col) in iproduct!(0..9, 0..9) {
+        if sudoku.get_cells()[row][col] == 0 {
+            counts[row][col] = template.cells[row][col].possible_values().len() as u8;
+        }
+    }
+    counts
+}
+
+/// Creates a template of the sudoku whose candidates are reduced by easy elimination, i.e. by repeatedly removing the
+/// values of the set cells from the candidates of the cells in the same row, column or square.
+pub(crate) fn reduced_template(sudoku: &Sudoku) -> SudokuTemplate {
+    let mut template = SudokuTemplate::from(sudoku.clone());
+    while (EliminatePossibilitiesUsingExistingSingles {}).solve(&mut template) {}
+    template
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::candidates::{candidate_counts, candidate_diff, reduced_template, validate_candidates, CandidateError};
+    use crate::Sudoku;
+
+    fn all_candidates() -> [[Vec<usize>; 9]; 9] {
+        std::array::from_fn(|_| std::array::from_fn(|_| (1..=9).collect()))
+    }
+
+    #[test]
+    fn candidate_diff_returns_removed_candidates() {
+        // Given two candidate grids, where some candidates were removed in the second one.
+        let before = all_candidates();
+        let mut after = all_candidates();
+        after[0][0] = vec![1, 2, 3];
+        after[4][7].retain(|&digit| digit != 5);
+
+        // When I compare them, then the removed candidates should be returned in row-major order.
+        assert_eq!(
+            candidate_diff(&before, &after),
+            vec![(0, 0, 4), (0, 0, 5), (0, 0, 6), (0, 0, 7), (0, 0, 8), (0, 0, 9), (4, 7, 5)],
+        );
+    }
+
+    #[test]
+    fn candidate_diff_ignores_added_candidates() {
+        // Given two candidate grids, where a candidate was added in the second one.
+        let mut before = all_candidates();
+        before[2][2] = vec![3];
+        let after = all_candidates();
+
+        // When I compare them, then nothing should be returned.
+        assert_eq!(candidate_diff(&before, &after), vec![]);
+    }
+
+    #[test]
+    fn candidate_counts_returns_counts_of_empty_cells() {
+        // Given a sudoku where the first row has three empty cells with the candidates [1, 5, 7].
+        let sudoku = ".234.6.89".to_string() + &".".repeat(72);
+        let sudoku = sudoku.parse::<Sudoku>().unwrap();
+
+        // When I get the candidate counts.
+        let counts = candidate_counts(&sudoku);
+
+        // Then the empty cells should have their number of candidates, and filled cells none.
+        assert_eq!(counts[0], [3, 0, 0, 0, 3, 0, 3, 0, 0]);
+        assert_eq!(counts[1], [7; 9]);
+        assert_eq!(counts[4], [9, 8, 8, 8, 9, 8, 9, 8, 8]);
+    }
+
+    #[test]
+    fn candidate_counts_of_empty_sudoku() {
+        // Given an empty sudoku.
+        let sudoku = ".".repeat(81).parse::<Sudoku>().unwrap();
+
+        // When I get the candidate counts, then every cell should have all candidates.
+        assert_eq!(candidate_counts(&sudoku), [[9; 9]; 9]);
+    }
+
+    const SUDOKU: &str = "\
+        ...6.94..\
+        29..8....\
+        .6...5...\
+        .........\
+        5......72\
+        912467583\
+        3..17..9.\
+        159..2...\
+        ...9...1.\
+    ";
+
+    /// Provides the candidates of the sudoku computed by easy elimination, where filled cells have their value as only
+    /// candidate.
+    fn valid_candidates(sudoku: &Sudoku) -> [[Vec<usize>; 9]; 9] {
+        let template = reduced_template(sudoku);
+        std::array::from_fn(|row| std::array::from_fn(|col| template.cells[row][col].possible_values()))
+    }
+
+    #[test]
+    fn validate_candidates_accepts_consistent_candidates() {
+        // Given a sudoku and its candidates computed by easy elimination.
+        let sudoku = SUDOKU.parse::<Sudoku>().unwrap();
+        let candidates = valid_candidates(&sudoku);
+
+        // When I validate the candidates, then they should be accepted.
+        assert_eq!(validate_candidates(&sudoku, &candidates), Ok(()));
+    }
+
+    #[test]
+    fn validate_candidates_rejects_candidate_placed_in_peer() {
+        // Given candidates where the first cell lists 6, which is already placed in the first row.
+        let sudoku = SUDOKU.parse::<Sudoku>().unwrap();
+        let mut candidates = valid_candidates(&sudoku);
+        candidates[0][0].push(6);
+
+        // When I validate the candidates, then the impossible candidate should be reported.
+        assert_eq!(validate_candidates(&sudoku, &candidates), Err(CandidateError::ContradictsPeer(0, 0, 6)));
+    }
+
+    #[test]
+    fn validate_candidates_rejects_inconsistent_cells() {
+        // Given other candidates than the value of a filled cell, and no candidates for a filled and an empty cell.
+        let sudoku = SUDOKU.parse::<Sudoku>().unwrap();
+        let mut with_filled_candidates = valid_candidates(&sudoku);
+        with_filled_candidates[1][0] = vec![2, 3];
+        let mut without_filled_candidates = valid_candidates(&sudoku);
+        without_filled_candidates[1][0] = vec![];
+        let mut without_candidates = valid_candidates(&sudoku);
+        without_candidates[0][0] = vec![];
+
+        // When I validate them, then the inconsistent cells should be reported.
+        assert_eq!(
+            validate_candidates(&sudoku, &with_filled_candidates),
+            Err(CandidateError::FilledCellHasOtherCandidates(1, 0)),
+        );
+        assert_eq!(
+            validate_candidates(&sudoku, &without_filled_candidates),
+            Err(CandidateError::FilledCellHasOtherCandidates(1, 0)),
+        );
+        assert_eq!(validate_candidates(&sudoku, &without_candidates), Err(CandidateError::NoCandidates(0, 0)));
+    }
+}
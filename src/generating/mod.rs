@@ -0,0 +1,3 @@
+pub mod pattern;
+pub mod perturbation;
+pub mod reduction;
@@ -0,0 +1,88 @@
+use itertools::{iproduct, Itertools};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use crate::solving::backtracking::{has_unique_solution, BacktrackingGrid};
+use crate::traits::Sudoku;
+
+/// Number of random solutions tried by `generate_for_pattern` before giving up.
+const MAX_ATTEMPTS: u64 = 100;
+
+/// Generates a proper puzzle whose givens are exactly the cells set in `pattern`, indexed by row and column. Random
+/// solutions are tried until the givens taken from one of them have a unique solution. Returns `None` if no such
+/// puzzle was found within a bounded number of attempts, e.g. because the pattern has too few cells. The same pattern
+/// always produces the same puzzle.
+pub fn generate_for_pattern(pattern: &[[bool; 9]; 9]) -> Option<Sudoku> {
+    (0..MAX_ATTEMPTS)
+        .map(|seed| {
+            let solution = random_solution(&mut StdRng::seed_from_u64(seed));
+            let mut cells = [[0; 9]; 9];
+            for (row, col) in iproduct!(0..9, 0..9).filter(|&(row, col)| pattern[row][col]) {
+                cells[row][col] = solution[row][col];
+            }
+            Sudoku::new(cells)
+        })
+        .find(has_unique_solution)
+}
+
+/// Provides a random complete sudoku grid.
+fn random_solution(rng: &mut StdRng) -> [[usize; 9]; 9] {
+    let mut grid = BacktrackingGrid::new(&Sudoku::new([[0; 9]; 9])).unwrap();
+    fill_randomly(&mut grid, rng);
+    grid.cells
+}
+
+/// Fills the empty cells of the grid by backtracking search, trying the candidates of each cell in random order.
+/// Returns `true` if the grid was filled, `false` if it has no solution.
+fn fill_randomly(grid: &mut BacktrackingGrid, rng: &mut StdRng) -> bool {
+    let Some((row, col, candidates)) = grid.most_constrained_empty_cell() else {
+        return true;
+    };
+
+    let mut values = (1..=9).filter(|value| candidates & (1 << value) != 0).collect_vec();
+    values.shuffle(rng);
+    for value in values {
+        grid.place(row, col, value);
+        if fill_randomly(grid, rng) {
+            return true;
+        }
+        grid.remove(row, col, value);
+    }
+
+    false
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use itertools::iproduct;
+
+    use crate::generating::pattern::generate_for_pattern;
+    use crate::solving::backtracking::has_unique_solution;
+
+    #[test]
+    fn generate_for_pattern_returns_proper_puzzle_matching_pattern() {
+        // Given a checkerboard pattern, which is symmetric under a 180° rotation.
+        let pattern: [[bool; 9]; 9] = std::array::from_fn(|row| std::array::from_fn(|col| (row + col) % 2 == 0));
+
+        // When I generate a puzzle for it.
+        let sudoku = generate_for_pattern(&pattern).unwrap();
+
+        // Then the puzzle should be proper, and its givens should match the pattern.
+        assert_eq!(has_unique_solution(&sudoku), true);
+        for (row, col) in iproduct!(0..9, 0..9) {
+            assert_eq!(sudoku.get_cells()[row][col] != 0, pattern[row][col], "Cell ({row}, {col}) does not match.");
+        }
+    }
+
+    #[test]
+    fn generate_for_pattern_returns_none_for_too_few_clues() {
+        // Given a pattern with a single cell, which can never have a unique solution.
+        let mut pattern = [[false; 9]; 9];
+        pattern[4][4] = true;
+
+        // When I generate a puzzle for it, then nothing should be found.
+        assert_eq!(generate_for_pattern(&pattern).is_none(), true);
+    }
+}
@@ -0,0 +1,87 @@
+use itertools::{iproduct, Itertools};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use crate::solving::backtracking::{find_solution, has_unique_solution};
+use crate::traits::Sudoku;
+
+/// Creates a puzzle related to the provided proper `sudoku` by applying up to `swaps` random clue moves. Each move
+/// either adds a clue taken from the solution or removes a clue that is not needed for the solution to stay unique, and
+/// is only accepted after verifying the result with `has_unique_solution`.
+///
+/// A cell is changed at most once, so the returned puzzle differs from the input as soon as one move was applied. The
+/// same `seed` always produces the same puzzle. If `sudoku` is not a proper puzzle, it is returned unchanged.
+pub fn perturb(sudoku: &Sudoku, swaps: usize, seed: u64) -> Sudoku {
+    if !has_unique_solution(sudoku) {
+        return sudoku.clone();
+    }
+    let solution = find_solution(sudoku).unwrap();
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut cells = *sudoku.get_cells();
+    let mut changed = [[false; 9]; 9];
+
+    for _ in 0..swaps {
+        let mut unchanged_cells = iproduct!(0..9, 0..9)
+            .filter(|&(row, col)| !changed[row][col])
+            .collect_vec();
+        unchanged_cells.shuffle(&mut rng);
+
+        // Apply the move for the first cell where it keeps the solution unique.
+        for (row, col) in unchanged_cells {
+            let mut candidate = cells;
+            candidate[row][col] = if cells[row][col] == 0 { solution[row][col] } else { 0 };
+
+            if has_unique_solution(&Sudoku::new(candidate)) {
+                cells = candidate;
+                changed[row][col] = true;
+                break;
+            }
+        }
+    }
+
+    Sudoku::new(cells)
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use crate::generating::perturbation::perturb;
+    use crate::solving::backtracking::has_unique_solution;
+    use crate::Sudoku;
+
+    const PROPER_SUDOKU: &str = "\
+        .....3...\
+        ....5.1..\
+        8.....2..\
+        ........4\
+        .9.....3.\
+        2...1....\
+        ..6......\
+        ....7.81.\
+        .43..9...\
+    ";
+
+    #[test]
+    fn perturb_returns_different_proper_sudoku() {
+        // Given a proper sudoku.
+        let sudoku = PROPER_SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I perturb it.
+        let perturbed = perturb(&sudoku, 5, 42);
+
+        // Then the result should still be proper and differ from the input.
+        assert_eq!(has_unique_solution(&perturbed), true);
+        assert_ne!(perturbed.to_string(), sudoku.to_string());
+    }
+
+    #[test]
+    fn perturb_is_deterministic_for_seed() {
+        // Given a proper sudoku.
+        let sudoku = PROPER_SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I perturb it twice with the same seed, then the results should be equal.
+        assert_eq!(perturb(&sudoku, 5, 7).to_string(), perturb(&sudoku, 5, 7).to_string());
+    }
+}
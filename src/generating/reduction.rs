@@ -0,0 +1,67 @@
+use crate::solving::backtracking::has_unique_solution;
+use crate::traits::Sudoku;
+
+/// Removes the cells of `solution` in the provided order, stopping just before a removal would make the solution not
+/// unique anymore. Returns the resulting proper puzzle, e.g. to get reproducible reductions. Cells after the stopping
+/// point are kept, even if removing them would keep the solution unique.
+pub fn reduce_until_unique(solution: &Sudoku, order: &[(usize, usize)]) -> Sudoku {
+    let mut cells = *solution.get_cells();
+
+    for &(row, col) in order {
+        let mut reduced = cells;
+        reduced[row][col] = 0;
+        if !has_unique_solution(&Sudoku::new(reduced)) {
+            break;
+        }
+        cells = reduced;
+    }
+
+    Sudoku::new(cells)
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use itertools::iproduct;
+
+    use crate::generating::reduction::reduce_until_unique;
+    use crate::solving::backtracking::has_unique_solution;
+    use crate::Sudoku;
+
+    const SOLUTION: &str = "\
+        835619427\
+        294783156\
+        761245839\
+        673528941\
+        548391672\
+        912467583\
+        386174295\
+        159832764\
+        427956318\
+    ";
+
+    #[test]
+    fn reduce_until_unique_stops_before_losing_uniqueness() {
+        // Given a solved sudoku and a row-major removal order.
+        let solution = SOLUTION.parse::<Sudoku>().unwrap();
+        let order = iproduct!(0..9, 0..9).collect::<Vec<_>>();
+
+        // When I reduce it.
+        let puzzle = reduce_until_unique(&solution, &order);
+
+        // Then the first 14 cells should be removed, as removing the 15th would make the solution not unique.
+        assert_eq!(puzzle.to_string(), ".".repeat(14) + &SOLUTION[14..]);
+        assert_eq!(puzzle.get_cells().iter().flatten().filter(|&&value| value != 0).count(), 67);
+        assert_eq!(has_unique_solution(&puzzle), true);
+    }
+
+    #[test]
+    fn reduce_until_unique_keeps_cells_outside_of_order() {
+        // Given a solved sudoku and a removal order of only two cells.
+        let solution = SOLUTION.parse::<Sudoku>().unwrap();
+
+        // When I reduce it, then only these cells should be removed.
+        let puzzle = reduce_until_unique(&solution, &[(0, 0), (8, 8)]);
+        assert_eq!(puzzle.to_string(), ".".to_string() + &SOLUTION[1..80] + ".");
+    }
+}
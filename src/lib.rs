@@ -1,9 +1,38 @@
-pub use solving::solver::solve;
+pub use analysis::candidate_entropy::candidate_entropy;
+pub use analysis::clues::{clue_importance, redundant_clues};
+pub use analysis::unit_completions::{unit_completions, MAX_UNIT_COMPLETIONS};
+pub use analysis::unit_pressure::unit_pressure;
+pub use candidates::{candidate_counts, candidate_diff, validate_candidates, CandidateError};
+pub use generating::pattern::generate_for_pattern;
+pub use generating::perturbation::perturb;
+pub use generating::reduction::reduce_until_unique;
+pub use playing::reset_to_givens;
+pub use printer::{candidates_to_string, diff_to_string, CandidateFormat};
+pub use solving::backtracking::{answer_key, count_solutions, has_unique_solution};
+pub use solving::cached_solver::CachedSolver;
+pub use solving::custom_strategy::PublicStrategy;
+pub use solving::debug::{solve_debug, Contradiction};
+pub use solving::hints::easiest_next_cell;
+pub use solving::search_trace::{solve_trace, SearchNode, SearchOutcome, MAX_SEARCH_NODES};
+pub use solving::solver::{
+    solve, solve_assuming_reduced, solve_by_tiers, solve_from_candidates, solve_latin_square, solve_op_count,
+    solve_step_count, solve_with_custom, solve_with_exclusions,
+};
+pub use solving::traits::Difficulty;
+pub use svg::SvgOptions;
 pub use traits::Sudoku;
+pub use traits::SudokuAlphaParsingError;
+pub use traits::SudokuJsonParsingError;
 pub use traits::SudokuStrParsingError;
+pub use traits::Unit;
 
+mod analysis;
+mod candidates;
+mod generating;
+mod playing;
 mod printer;
 mod solving;
+mod svg;
 mod validator;
 mod utils;
 mod traits;
@@ -0,0 +1,58 @@
+use itertools::iproduct;
+
+use crate::traits::Sudoku;
+
+/// Restarts a played puzzle, by clearing all the cells of `played` that are empty in the `original` puzzle, so that
+/// only the givens remain.
+pub fn reset_to_givens(original: &Sudoku, played: &Sudoku) -> Sudoku {
+    let mut cells = *played.get_cells();
+    for (row, col) in iproduct!(0..9, 0..9) {
+        if original.get_cells()[row][col] == 0 {
+            cells[row][col] = 0;
+        }
+    }
+    Sudoku::new(cells)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::playing::reset_to_givens;
+    use crate::Sudoku;
+
+    const ORIGINAL: &str = "\
+        ...6.94..\
+        29..8....\
+        .6...5...\
+        .........\
+        5......72\
+        912467583\
+        3..17..9.\
+        159..2...\
+        ...9...1.\
+    ";
+
+    const PLAYED: &str = "\
+        835619427\
+        294783156\
+        761245839\
+        67352894.\
+        548391672\
+        912467583\
+        386174295\
+        159832...\
+        4.7956318\
+    ";
+
+    #[test]
+    fn reset_to_givens_clears_cells_filled_by_the_user() {
+        // Given an original puzzle and a partially played version of it.
+        let original = ORIGINAL.parse::<Sudoku>().unwrap();
+        let played = PLAYED.parse::<Sudoku>().unwrap();
+
+        // When I reset the played puzzle to its givens.
+        let reset = reset_to_givens(&original, &played);
+
+        // Then only the original clues should remain.
+        assert_eq!(reset.get_cells(), original.get_cells());
+    }
+}
@@ -1,30 +1,178 @@
-use crate::traits::SudokuTemplate;
+use crate::candidates::reduced_template;
+use crate::traits::{Sudoku, SudokuTemplate};
 
+/// Formats in which the candidates of a cell can be rendered.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CandidateFormat {
+    /// Each candidate is rendered at its own position, e.g. `1   5 7  ` for the candidates [1, 5, 7].
+    Positional,
+    /// The candidates are rendered next to each other and left-aligned, e.g. `157      ` for the candidates [1, 5, 7].
+    Compact,
+}
+
+/// Renders the candidates of every cell of the sudoku, as computed by easy elimination, in a grid.
+pub fn candidates_to_string(sudoku: &Sudoku, format: CandidateFormat) -> String {
+    template_to_string(&reduced_template(sudoku), format)
+}
+
+#[allow(dead_code)]
 pub(crate) fn print(sudoku: &SudokuTemplate) {
     println!("[DEBUG] current template state: ");
-    println!(" {} ", "-".repeat(111));
+    print!("{}", template_to_string(sudoku, CandidateFormat::Positional));
+}
+
+fn template_to_string(sudoku: &SudokuTemplate, format: CandidateFormat) -> String {
+    let mut s = format!(" {} \n", "-".repeat(111));
     for x in 0..9 {
-        print!("|| ");
+        s.push_str("|| ");
         for y in 0..9 {
-            let possibilities = sudoku.cells[x][y].possible_values();
-            for n in 1..=9 {
-                if possibilities.contains(&n) {
-                    print!("{}", n);
-                } else {
-                    print!(" ");
-                }
-            }
-            print!(" |");
+            s.push_str(&cell_candidates_to_string(&sudoku.cells[x][y].possible_values(), format));
+            s.push_str(" |");
             if (y + 1) % 3 == 0 {
-                print!("|");
+                s.push('|');
             }
-            print!(" ");
+            s.push(' ');
         }
-        println!();
+        s.push('\n');
         if (x + 1) % 3 == 0 {
-            println!(" {} ", "-".repeat(111));
+            s.push_str(&format!(" {} \n", "-".repeat(111)));
         } else {
-            println!("|{}|", "|           |           |           |".repeat(3));
+            s.push_str(&format!("|{}|\n", "|           |           |           |".repeat(3)));
         }
     }
+    s
+}
+
+/// Renders the candidates of a cell in the provided format, always using 9 characters.
+fn cell_candidates_to_string(possibilities: &[usize], format: CandidateFormat) -> String {
+    match format {
+        CandidateFormat::Positional => (1..=9)
+            .map(|n| if possibilities.contains(&n) { char::from_digit(n as u32, 10).unwrap() } else { ' ' })
+            .collect(),
+        CandidateFormat::Compact => format!("{:<9}", possibilities.iter().map(|n| n.to_string()).collect::<String>()),
+    }
+}
+
+/// Renders `after` as a grid in which the cells that differ from `before` are wrapped in brackets. Empty cells are
+/// rendered as dots.
+///
+/// ```text
+/// +---------+---------+---------+
+/// | 8  3 [5]| 6  .  9 | 4  .  . |
+/// ...
+/// ```
+pub fn diff_to_string(before: &Sudoku, after: &Sudoku) -> String {
+    let separator = "+---------+---------+---------+";
+    let mut lines = vec![separator.to_string()];
+
+    for row in 0..9 {
+        let mut line = String::from("|");
+        for col in 0..9 {
+            let value = after.get_cells()[row][col];
+            let symbol = if value == 0 { '.' } else { char::from_digit(value as u32, 10).unwrap_or('.') };
+            if value == before.get_cells()[row][col] {
+                line.push_str(&format!(" {symbol} "));
+            } else {
+                line.push_str(&format!("[{symbol}]"));
+            }
+            if (col + 1) % 3 == 0 {
+                line.push('|');
+            }
+        }
+        lines.push(line);
+        if (row + 1) % 3 == 0 {
+            lines.push(separator.to_string());
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use crate::printer::{candidates_to_string, diff_to_string, CandidateFormat};
+    use crate::Sudoku;
+
+    const BEFORE: &str = "\
+        ...6.94..\
+        29..8....\
+        .6...5...\
+        .........\
+        5......72\
+        912467583\
+        3..17..9.\
+        159..2...\
+        ...9...1.\
+    ";
+
+    const AFTER: &str = "\
+        8..6.94..\
+        29..8....\
+        .6...5...\
+        .........\
+        5......72\
+        912467583\
+        3..17..9.\
+        159..2...\
+        ...9...18\
+    ";
+
+    #[test]
+    fn diff_to_string_marks_only_changed_cells() {
+        // Given two sudokus that differ in the first and the last cell.
+        let before = BEFORE.parse::<Sudoku>().unwrap();
+        let after = AFTER.parse::<Sudoku>().unwrap();
+
+        // When I render their difference.
+        let diff = diff_to_string(&before, &after);
+        let lines = diff.lines().collect::<Vec<_>>();
+
+        // Then only the changed cells should be marked.
+        assert_eq!(lines.len(), 13);
+        assert_eq!(lines[0], "+---------+---------+---------+");
+        assert_eq!(lines[1], "|[8] .  . | 6  .  9 | 4  .  . |");
+        assert_eq!(lines[7], "| 9  1  2 | 4  6  7 | 5  8  3 |");
+        assert_eq!(lines[11], "| .  .  . | 9  .  . | .  1 [8]|");
+        assert_eq!(diff.matches('[').count(), 2);
+    }
+
+    #[test]
+    fn diff_to_string_marks_nothing_for_equal_sudokus() {
+        // Given a sudoku.
+        let sudoku = BEFORE.parse::<Sudoku>().unwrap();
+
+        // When I render its difference with itself, then no cell should be marked.
+        assert_eq!(diff_to_string(&sudoku, &sudoku).contains('['), false);
+    }
+
+    #[test]
+    fn candidates_to_string_renders_positional_candidates() {
+        // Given a sudoku where the first cell has the candidates [1, 5, 7].
+        let sudoku = ".234.6.89".to_string() + &".".repeat(72);
+        let sudoku = sudoku.parse::<Sudoku>().unwrap();
+
+        // When I render its candidates in the positional format.
+        let candidates = candidates_to_string(&sudoku, CandidateFormat::Positional);
+
+        // Then each candidate should be rendered at its position.
+        let lines = candidates.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 19);
+        assert!(lines[1].starts_with("|| 1   5 7   |  2        |   3       || "));
+    }
+
+    #[test]
+    fn candidates_to_string_renders_compact_candidates() {
+        // Given a sudoku where the first cell has the candidates [1, 5, 7].
+        let sudoku = ".234.6.89".to_string() + &".".repeat(72);
+        let sudoku = sudoku.parse::<Sudoku>().unwrap();
+
+        // When I render its candidates in the compact format.
+        let candidates = candidates_to_string(&sudoku, CandidateFormat::Compact);
+
+        // Then the candidates should be rendered next to each other.
+        let lines = candidates.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 19);
+        assert!(lines[1].starts_with("|| 157       | 2         | 3         || "));
+    }
 }
@@ -0,0 +1,266 @@
+use itertools::iproduct;
+
+use crate::traits::{Sudoku, ALL_DIGITS};
+
+/// Counts the solutions of a sudoku by exhaustive backtracking search, stopping as soon as `limit` solutions have been
+/// found. A sudoku whose entries already conflict with each other has no solutions, and a `limit` of zero is always
+/// reached without searching.
+pub fn count_solutions(sudoku: &Sudoku, limit: usize) -> usize {
+    let mut count = 0;
+    if limit == 0 {
+        return count;
+    }
+    if let Some(mut grid) = BacktrackingGrid::new(sudoku) {
+        grid.search(&mut |_| {
+            count += 1;
+            count >= limit
+        });
+    }
+    count
+}
+
+/// Returns `true` if the sudoku has exactly one solution, `false` otherwise.
+pub fn has_unique_solution(sudoku: &Sudoku) -> bool {
+    count_solutions(sudoku, 2) == 1
+}
+
+/// Provides the complete solution of a puzzle, e.g. to check the entries of a player against it. If the puzzle has
+/// several solutions, the first one found is returned. Returns `None` if the puzzle has no solution.
+pub fn answer_key(puzzle: &Sudoku) -> Option<[[usize; 9]; 9]> {
+    find_solution(puzzle)
+}
+
+/// Returns the first solution found by backtracking search, or `None` if the sudoku has no solution.
+pub(crate) fn find_solution(sudoku: &Sudoku) -> Option<[[usize; 9]; 9]> {
+    let mut solution = None;
+    if let Some(mut grid) = BacktrackingGrid::new(sudoku) {
+        grid.search(&mut |cells| {
+            solution = Some(*cells);
+            true
+        });
+    }
+    solution
+}
+
+/// Sudoku grid that keeps track of the digits used in each row, column and square, so that the candidates of a cell
+/// can be computed without scanning its peers.
+pub(crate) struct BacktrackingGrid {
+    pub(crate) cells: [[usize; 9]; 9],
+    rows: [u16; 9],
+    columns: [u16; 9],
+    squares: [u16; 9],
+}
+
+impl BacktrackingGrid {
+    /// Creates a grid from a sudoku. Returns `None` if the sudoku contains invalid values or conflicting entries.
+    pub(crate) fn new(sudoku: &Sudoku) -> Option<BacktrackingGrid> {
+        let mut grid = BacktrackingGrid {
+            cells: [[0; 9]; 9],
+            rows: [0; 9],
+            columns: [0; 9],
+            squares: [0; 9],
+        };
+
+        for (row, col) in iproduct!(0..9, 0..9) {
+            let value = sudoku.get_cells()[row][col];
+            if value == 0 {
+                continue;
+            }
+            if value > 9 || grid.candidates(row, col) & (1 << value) == 0 {
+                return None;
+            }
+            grid.place(row, col, value);
+        }
+
+        Some(grid)
+    }
+
+    /// Provides the digits that can be placed in a cell as a bitmask.
+    pub(crate) fn candidates(&self, row: usize, col: usize) -> u16 {
+        !(self.rows[row] | self.columns[col] | self.squares[3 * (row / 3) + col / 3]) & ALL_DIGITS
+    }
+
+    pub(crate) fn place(&mut self, row: usize, col: usize, value: usize) {
+        self.cells[row][col] = value;
+        self.rows[row] |= 1 << value;
+        self.columns[col] |= 1 << value;
+        self.squares[3 * (row / 3) + col / 3] |= 1 << value;
+    }
+
+    pub(crate) fn remove(&mut self, row: usize, col: usize, value: usize) {
+        self.cells[row][col] = 0;
+        self.rows[row] &= !(1 << value);
+        self.columns[col] &= !(1 << value);
+        self.squares[3 * (row / 3) + col / 3] &= !(1 << value);
+    }
+
+    /// Finds the empty cell with the fewest candidates, with ties broken in row-major order. Returns the row, column
+    /// and candidates bitmask of the cell, or `None` if the grid is full.
+    pub(crate) fn most_constrained_empty_cell(&self) -> Option<(usize, usize, u16)> {
+        let mut most_constrained: Option<(usize, usize, u16)> = None;
+
+        for index in 0..81 {
+            let (row, col) = (index / 9, index % 9);
+            if self.cells[row][col] != 0 {
+                continue;
+            }
+            let candidates = self.candidates(row, col);
+            if most_constrained.is_none_or(|(_, _, other)| candidates.count_ones() < other.count_ones()) {
+                most_constrained = Some((row, col, candidates));
+                // A cell cannot be more constrained than having at most one candidate.
+                if candidates.count_ones() <= 1 {
+                    break;
+                }
+            }
+        }
+
+        most_constrained
+    }
+
+    /// Searches for solutions depth-first, always branching on the most constrained empty cell. The `visit` callback
+    /// is called with every solution found and returns `true` to stop the search. Returns `true` if the search was
+    /// stopped, `false` if it was exhausted.
+    pub(crate) fn search<F: FnMut(&[[usize; 9]; 9]) -> bool>(&mut self, visit: &mut F) -> bool {
+        let Some((row, col, candidates)) = self.most_constrained_empty_cell() else {
+            return visit(&self.cells);
+        };
+
+        for value in (1..=9).filter(|value| candidates & (1 << value) != 0) {
+            self.place(row, col, value);
+            let stop = self.search(visit);
+            self.remove(row, col, value);
+            if stop {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use itertools::iproduct;
+
+    use crate::solving::backtracking::{answer_key, count_solutions, find_solution, has_unique_solution};
+    use crate::Sudoku;
+
+    const EMPTY_SUDOKU: &str = "\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+    ";
+
+    const PROPER_SUDOKU: &str = "\
+        ...6.94..\
+        29..8....\
+        .6...5...\
+        .........\
+        5......72\
+        912467583\
+        3..17..9.\
+        159..2...\
+        ...9...1.\
+    ";
+
+    const PROPER_SUDOKU_SOLUTION: &str = "\
+        835619427\
+        294783156\
+        761245839\
+        673528941\
+        548391672\
+        912467583\
+        386174295\
+        159832764\
+        427956318\
+    ";
+
+    const CONFLICTING_SUDOKU: &str = "\
+        11.......\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+    ";
+
+    #[test]
+    fn count_solutions_stops_at_limit() {
+        // Given an empty sudoku, which has a huge number of solutions.
+        let sudoku = EMPTY_SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I count its solutions, then the count should stop at the limit.
+        assert_eq!(count_solutions(&sudoku, 5), 5);
+        assert_eq!(has_unique_solution(&sudoku), false);
+    }
+
+    #[test]
+    fn count_solutions_with_zero_limit_is_zero() {
+        // Given an empty sudoku, which has a huge number of solutions.
+        let sudoku = EMPTY_SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I count its solutions with a zero limit, then no solution should be counted.
+        assert_eq!(count_solutions(&sudoku, 0), 0);
+    }
+
+    #[test]
+    fn count_solutions_finds_single_solution_of_proper_sudoku() {
+        // Given a proper sudoku.
+        let sudoku = PROPER_SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I count its solutions, then there should be exactly one, matching the expected solution.
+        assert_eq!(count_solutions(&sudoku, 10), 1);
+        assert_eq!(has_unique_solution(&sudoku), true);
+        assert_eq!(
+            Sudoku::new(find_solution(&sudoku).unwrap()).to_string(),
+            PROPER_SUDOKU_SOLUTION,
+        );
+    }
+
+    #[test]
+    fn count_solutions_finds_no_solution_for_conflicting_sudoku() {
+        // Given a sudoku with two equal values in the same row.
+        let sudoku = CONFLICTING_SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I count its solutions, then there should be none.
+        assert_eq!(count_solutions(&sudoku, 10), 0);
+        assert_eq!(find_solution(&sudoku), None);
+    }
+
+    #[test]
+    fn answer_key_returns_solution_consistent_with_givens() {
+        // Given a proper sudoku.
+        let sudoku = PROPER_SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I get its answer key.
+        let answer_key = answer_key(&sudoku).unwrap();
+
+        // Then every cell should be filled, and the givens should be kept.
+        assert_eq!(Sudoku::new(answer_key).to_string(), PROPER_SUDOKU_SOLUTION);
+        for (x, y) in iproduct!(0..9, 0..9) {
+            let given = sudoku.get_cells()[x][y];
+            if given != 0 {
+                assert_eq!(answer_key[x][y], given, "Given at ({x}, {y}) was changed.");
+            }
+        }
+    }
+
+    #[test]
+    fn answer_key_returns_none_for_unsolvable_sudoku() {
+        // Given a sudoku with conflicting entries.
+        let sudoku = CONFLICTING_SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I get its answer key, then nothing should be returned.
+        assert_eq!(answer_key(&sudoku), None);
+    }
+}
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use crate::solving::solver::solve;
+use crate::traits::Sudoku;
+
+/// Solver that remembers the results of previous calls, keyed by the fingerprint of the solved sudoku. Useful when the
+/// same sudoku is solved repeatedly, e.g. on every keystroke of an editor.
+///
+/// The cache is not synchronized, so sharing a `CachedSolver` between threads requires external locking.
+#[derive(Default)]
+pub struct CachedSolver {
+    cache: HashMap<u64, Sudoku>,
+    solve_count: usize,
+}
+
+impl CachedSolver {
+    pub fn new() -> CachedSolver {
+        CachedSolver::default()
+    }
+
+    /// Solves the sudoku, or returns the cached result if it was solved before.
+    pub fn solve(&mut self, sudoku: &Sudoku) -> Sudoku {
+        self.cache
+            .entry(sudoku.fingerprint())
+            .or_insert_with(|| {
+                self.solve_count += 1;
+                solve(sudoku)
+            })
+            .clone()
+    }
+
+    /// Removes all cached results.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solving::cached_solver::CachedSolver;
+    use crate::solving::solver::solve;
+    use crate::Sudoku;
+
+    const SUDOKU: &str = "\
+        ...6.94..\
+        29..8....\
+        .6...5...\
+        .........\
+        5......72\
+        912467583\
+        3..17..9.\
+        159..2...\
+        ...9...1.\
+    ";
+
+    #[test]
+    fn solve_returns_cached_result() {
+        // Given a cached solver and a sudoku.
+        let mut solver = CachedSolver::new();
+        let sudoku = SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I solve the sudoku twice.
+        let first = solver.solve(&sudoku);
+        let second = solver.solve(&sudoku);
+
+        // Then both results should be correct, and the sudoku should only have been solved once.
+        assert_eq!(first.to_string(), solve(&sudoku).to_string());
+        assert_eq!(second.to_string(), first.to_string());
+        assert_eq!(solver.solve_count, 1);
+    }
+
+    #[test]
+    fn clear_removes_cached_results() {
+        // Given a cached solver that has already solved a sudoku.
+        let mut solver = CachedSolver::new();
+        let sudoku = SUDOKU.parse::<Sudoku>().unwrap();
+        solver.solve(&sudoku);
+
+        // When I clear the cache and solve the sudoku again, then it should be solved again.
+        solver.clear();
+        solver.solve(&sudoku);
+        assert_eq!(solver.solve_count, 2);
+    }
+}
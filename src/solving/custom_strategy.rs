@@ -0,0 +1,43 @@
+use crate::solving::traits::{Difficulty, SudokuSolvingStrategy};
+use crate::traits::SudokuTemplate;
+
+/// Defines a custom solving strategy, which can be applied alongside the implemented strategies with
+/// `solve_with_custom`. The strategy operates on the candidates of each cell, indexed by row and column, where a cell
+/// with a single candidate is considered set to that candidate.
+pub trait PublicStrategy {
+    /// Applies a solving technique to the candidates. Returns `true` if any candidates were changed, `false` otherwise.
+    fn solve(&self, candidates: &mut [[Vec<usize>; 9]; 9]) -> bool;
+
+    /// Provides the human readable name of the strategy.
+    fn name(&self) -> &'static str;
+
+    /// Provides the difficulty level of the strategy. Custom strategies are considered hard unless stated otherwise.
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Hard
+    }
+}
+
+/// Adapts a `PublicStrategy` to be applied to a `SudokuTemplate` like the implemented strategies.
+pub(crate) struct CustomStrategy(pub(crate) Box<dyn PublicStrategy>);
+
+impl SudokuSolvingStrategy for CustomStrategy {
+    fn solve(&self, sudoku: &mut SudokuTemplate) -> bool {
+        let mut candidates = <[[Vec<usize>; 9]; 9]>::from(&*sudoku);
+        if !self.0.solve(&mut candidates) {
+            return false;
+        }
+
+        let solved = SudokuTemplate::from(&candidates);
+        let made_changes = solved != *sudoku;
+        *sudoku = solved;
+        made_changes
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        self.0.difficulty()
+    }
+
+    fn name(&self) -> &'static str {
+        self.0.name()
+    }
+}
@@ -0,0 +1,121 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use itertools::iproduct;
+
+use crate::solving::propagate_singles::propagate_singles;
+use crate::solving::solver::implemented_strategies;
+use crate::traits::{Sudoku, SudokuTemplate};
+
+/// Name reported for contradictions caused by propagating the set values to the possibilities of their peers.
+const PROPAGATION_NAME: &str = "Singles propagation";
+
+/// Describes the first contradiction found while solving, i.e. the first cell left without any possible value, along
+/// with the name of the strategy that removed its last possible value.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Contradiction {
+    /// The row of the cell without any possible value.
+    pub row: usize,
+    /// The column of the cell without any possible value.
+    pub col: usize,
+    /// The name of the strategy that removed the last possible value of the cell.
+    pub strategy: &'static str,
+}
+
+impl Display for Contradiction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Cell ({}, {}) has no possible value left after applying {}", self.row, self.col, self.strategy)
+    }
+}
+
+impl Error for Contradiction {}
+
+/// Solves a sudoku like `solve`, but checks for contradictions after each step, e.g. to find out why a sudoku cannot
+/// be solved. Returns the first contradiction found, or the solved sudoku if there was none.
+pub fn solve_debug(sudoku: &Sudoku) -> Result<Sudoku, Contradiction> {
+    let mut template = SudokuTemplate::from(sudoku.clone());
+    let strategies = implemented_strategies();
+
+    loop {
+        propagate_singles(&mut template);
+        check_contradiction(&template, PROPAGATION_NAME)?;
+
+        let Some(strategy) = strategies.iter().find(|s| s.solve(&mut template)) else {
+            break;
+        };
+        check_contradiction(&template, strategy.name())?;
+    }
+
+    Ok(Sudoku::from(template))
+}
+
+/// Returns the first cell without any possible value, in row-major order, as a contradiction caused by `strategy`.
+fn check_contradiction(template: &SudokuTemplate, strategy: &'static str) -> Result<(), Contradiction> {
+    match iproduct!(0..9, 0..9).find(|&(row, col)| template.cells[row][col].possible_values().is_empty()) {
+        Some((row, col)) => Err(Contradiction { row, col, strategy }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solving::debug::{solve_debug, Contradiction, PROPAGATION_NAME};
+    use crate::Sudoku;
+
+    const SUDOKU: &str = "\
+        ...6.94..\
+        29..8....\
+        .6...5...\
+        .........\
+        5......72\
+        912467583\
+        3..17..9.\
+        159..2...\
+        ...9...1.\
+    ";
+
+    const SOLUTION: &str = "\
+        835619427\
+        294783156\
+        761245839\
+        673528941\
+        548391672\
+        912467583\
+        386174295\
+        159832764\
+        427956318\
+    ";
+
+    const SUDOKU_WITHOUT_CANDIDATE: &str = "\
+        12345678.\
+        ........9\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+    ";
+
+    #[test]
+    fn solve_debug_reports_cell_without_possible_value() {
+        // Given a sudoku where the last cell of the first row cannot hold any value.
+        let sudoku = SUDOKU_WITHOUT_CANDIDATE.parse::<Sudoku>().unwrap();
+
+        // When I solve it, then the contradiction should be reported for that cell.
+        assert_eq!(
+            solve_debug(&sudoku).unwrap_err(),
+            Contradiction { row: 0, col: 8, strategy: PROPAGATION_NAME },
+        );
+    }
+
+    #[test]
+    fn solve_debug_solves_sudoku_without_contradiction() {
+        // Given a proper sudoku.
+        let sudoku = SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I solve it, then it should be solved.
+        assert_eq!(solve_debug(&sudoku).unwrap().to_string(), SOLUTION);
+    }
+}
@@ -33,7 +33,7 @@ pub(crate) struct EliminatePossibilitiesUsingExistingSingles;
 impl EliminatePossibilitiesUsingExistingSingles {
     /// For each cell that has a value, eliminates the value as a possibility from other cells in the same row or
     /// column.
-    fn in_rows_and_columns(sudoku: &mut SudokuTemplate) -> bool {
+    pub(crate) fn in_rows_and_columns(sudoku: &mut SudokuTemplate) -> bool {
         let mut made_changes = false;
 
         // For each cell
@@ -94,9 +94,14 @@ impl SudokuSolvingStrategy for EliminatePossibilitiesUsingExistingSingles {
     fn difficulty(&self) -> Difficulty {
         Difficulty::Easy
     }
+
+    fn name(&self) -> &'static str {
+        "Existing singles"
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison, clippy::clone_on_copy)]
 mod tests {
     use crate::solving::eliminate_possibilities_using_existing_singles::EliminatePossibilitiesUsingExistingSingles;
     use crate::solving::traits::{Difficulty, SudokuSolvingStrategy};
@@ -8,20 +8,20 @@ use crate::traits::SudokuTemplate;
 pub(crate) struct EliminatePossibilitiesUsingHiddenCombinationsGroups;
 
 impl EliminatePossibilitiesUsingHiddenCombinationsGroups {
-    fn in_rows(sudoku: &mut SudokuTemplate) -> bool {
+    pub(crate) fn in_rows(sudoku: &mut SudokuTemplate) -> bool {
         let mut made_changes = false;
 
         for row in 0..9 {
-            let missing_values = sudoku.get_missing_values_in_row(row);
-            for combination_len in 2..=min(missing_values.len(), 4) {
-                for ref_combination in missing_values.iter().combinations(combination_len) {
-                    let combination = &ref_combination.into_iter().map(|x| *x).collect_vec();
+            let missing_values = sudoku.missing_mask_row(row);
+            let missing_count = missing_values.count_ones() as usize;
+            for combination_len in 2..=min(missing_count, 4) {
+                for combination in SudokuTemplate::mask_combinations(missing_values, combination_len) {
                     let containing_cells = (0..9)
                         .zip([row; 9])
                         .filter(|(y, x)| sudoku.cells[*x][*y].contains_any_possibilities(combination))
                         .map(|(y, x)| (x, y))
                         .collect_vec();
-                    if combination_len == containing_cells.len() && combination_len != missing_values.len() {
+                    if combination_len == containing_cells.len() && combination_len != missing_count {
                         for (x, y) in containing_cells {
                             made_changes |= sudoku.cells[x][y].remove_possibilities_outside_of(combination);
                         }
@@ -33,19 +33,19 @@ impl EliminatePossibilitiesUsingHiddenCombinationsGroups {
         made_changes
     }
 
-    fn in_columns(sudoku: &mut SudokuTemplate) -> bool {
+    pub(crate) fn in_columns(sudoku: &mut SudokuTemplate) -> bool {
         let mut made_changes = false;
 
         for column in 0..9 {
-            let missing_values = sudoku.get_missing_values_in_column(column);
-            for combination_len in 2..=min(missing_values.len(), 4) {
-                for ref_combination in missing_values.iter().combinations(combination_len) {
-                    let combination = &ref_combination.into_iter().map(|x| *x).collect_vec();
+            let missing_values = sudoku.missing_mask_column(column);
+            let missing_count = missing_values.count_ones() as usize;
+            for combination_len in 2..=min(missing_count, 4) {
+                for combination in SudokuTemplate::mask_combinations(missing_values, combination_len) {
                     let containing_cells = (0..9)
                         .zip([column; 9])
                         .filter(|(x, y)| sudoku.cells[*x][*y].contains_any_possibilities(combination))
                         .collect_vec();
-                    if combination_len == containing_cells.len() && combination_len != missing_values.len() {
+                    if combination_len == containing_cells.len() && combination_len != missing_count {
                         for (x, y) in containing_cells {
                             made_changes |= sudoku.cells[x][y].remove_possibilities_outside_of(combination);
                         }
@@ -61,15 +61,15 @@ impl EliminatePossibilitiesUsingHiddenCombinationsGroups {
         let mut made_changes = false;
 
         for (sq_row, sq_column) in iproduct!((0..3), (0..3)) {
-            let missing_values = sudoku.get_missing_values_in_square(sq_row, sq_column);
-            for combination_len in 2..=min(missing_values.len(), 4) {
-                for ref_combination in missing_values.iter().combinations(combination_len) {
-                    let combination = &ref_combination.into_iter().map(|x| *x).collect_vec();
-                    let containing_cells = iproduct!((0..3), (0..3))
-                        .map(|(x, y)| (3 * sq_row + x, 3 * sq_column + y))
+            let missing_values = sudoku.missing_mask_square(sq_row, sq_column);
+            let missing_count = missing_values.count_ones() as usize;
+            for combination_len in 2..=min(missing_count, 4) {
+                for combination in SudokuTemplate::mask_combinations(missing_values, combination_len) {
+                    let containing_cells = SudokuTemplate::square_cells(sq_row, sq_column)
+                        .into_iter()
                         .filter(|(x, y)| sudoku.cells[*x][*y].contains_any_possibilities(combination))
                         .collect_vec();
-                    if combination_len == containing_cells.len() && combination_len != missing_values.len() {
+                    if combination_len == containing_cells.len() && combination_len != missing_count {
                         for (x, y) in containing_cells {
                             made_changes |= sudoku.cells[x][y].remove_possibilities_outside_of(combination);
                         }
@@ -92,4 +92,8 @@ impl SudokuSolvingStrategy for EliminatePossibilitiesUsingHiddenCombinationsGrou
     fn difficulty(&self) -> Difficulty {
         Difficulty::Medium
     }
+
+    fn name(&self) -> &'static str {
+        "Hidden groups"
+    }
 }
\ No newline at end of file
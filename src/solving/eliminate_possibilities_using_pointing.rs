@@ -21,10 +21,7 @@ impl EliminatePossibilitiesUsingPointing {
                     let sq_row = row / 3;
                     let sq_col = squares[0];
 
-                    for (x, y) in iproduct!((0..3), (0..3)) {
-                        let cell_row = 3 * sq_row + x;
-                        let cell_col = 3 * sq_col + y;
-
+                    for (cell_row, cell_col) in SudokuTemplate::square_cells(sq_row, sq_col) {
                         if cell_row != row {
                             made_changes |= sudoku.cells[cell_row][cell_col].remove_possibility(value);
                         }
@@ -45,10 +42,7 @@ impl EliminatePossibilitiesUsingPointing {
                     let sq_row = squares[0];
                     let sq_col = col / 3;
 
-                    for (x, y) in iproduct!((0..3), (0..3)) {
-                        let cell_row = 3 * sq_row + x;
-                        let cell_col = 3 * sq_col + y;
-
+                    for (cell_row, cell_col) in SudokuTemplate::square_cells(sq_row, sq_col) {
                         if cell_col != col {
                             made_changes |= sudoku.cells[cell_row][cell_col].remove_possibility(value);
                         }
@@ -67,7 +61,7 @@ impl EliminatePossibilitiesUsingPointing {
 
         // For each square
         for (sq_row, sq_col) in iproduct!((0..3), (0..3)) {
-            let missing_values = sudoku.get_values_in_square(sq_row, sq_col);
+            let missing_values = sudoku.get_missing_values_in_square(sq_row, sq_col);
 
             // For each missing value
             for value in missing_values {
@@ -78,19 +72,16 @@ impl EliminatePossibilitiesUsingPointing {
                 let mut value_col = None;
 
                 // For each cell in the square
-                for (x, y) in iproduct!((0..3), (0..3)) {
-                    // Calculate row and column of the cell
-                    let row = 3 * sq_row + x;
-                    let col = 3 * sq_col + y;
+                for (row, col) in SudokuTemplate::square_cells(sq_row, sq_col) {
                     let cell = &sudoku.cells[row][col];
 
                     // If cell is empty and `value` is a possibility
                     if cell.is_empty() && cell.possible_values().contains(&value) {
                         // Mark the first row and column where the value is a possibility
-                        if value_row == None {
+                        if value_row.is_none() {
                             value_row = Some(row);
                         }
-                        if value_col == None {
+                        if value_col.is_none() {
                             value_col = Some(col);
                         }
 
@@ -101,8 +92,7 @@ impl EliminatePossibilitiesUsingPointing {
                 }
 
                 // If the cells where the value is possible are part of a single row
-                if value_in_one_row && value_row.is_some() {
-                    let row = value_row.unwrap();
+                if let (true, Some(row)) = (value_in_one_row, value_row) {
                     let columns_in_square = [3 * sq_col, 3 * sq_col + 1, 3 * sq_col + 2];
                     let columns_not_in_square = (0..9).filter(|c| !columns_in_square.contains(c)).collect_vec();
 
@@ -112,8 +102,7 @@ impl EliminatePossibilitiesUsingPointing {
                 }
 
                 // If the cells where the value is possible are part of a single column
-                if value_in_one_col && value_col.is_some() {
-                    let col = value_col.unwrap();
+                if let (true, Some(col)) = (value_in_one_col, value_col) {
                     let rows_in_square = [3 * sq_row, 3 * sq_row + 1, 3 * sq_row + 2];
                     let rows_not_in_square = (0..9).filter(|c| !rows_in_square.contains(c)).collect_vec();
 
@@ -137,4 +126,34 @@ impl SudokuSolvingStrategy for EliminatePossibilitiesUsingPointing {
     fn difficulty(&self) -> Difficulty {
         Difficulty::Medium
     }
+
+    fn name(&self) -> &'static str {
+        "Pointing"
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use crate::solving::eliminate_possibilities_using_pointing::EliminatePossibilitiesUsingPointing;
+    use crate::traits::SudokuTemplate;
+
+    #[test]
+    fn in_squares_removes_value_pointing_along_a_row() {
+        // Given a grid where 1 is only possible in the first row of the first square.
+        let mut candidates: [[Vec<usize>; 9]; 9] = std::array::from_fn(|_| std::array::from_fn(|_| (1..=9).collect()));
+        for (row, col) in [(1, 0), (1, 1), (1, 2), (2, 0), (2, 1), (2, 2)] {
+            candidates[row][col].retain(|&value| value != 1);
+        }
+        let mut sudoku = SudokuTemplate::from(&candidates);
+
+        // When I apply pointing in squares.
+        let made_changes = EliminatePossibilitiesUsingPointing::in_squares(&mut sudoku);
+
+        // Then 1 should be removed from the rest of the first row, but not from the first square.
+        assert_eq!(made_changes, true);
+        assert_eq!((3..9).any(|col| sudoku.cells[0][col].contains_possibility(1)), false);
+        assert_eq!((0..3).all(|col| sudoku.cells[0][col].contains_possibility(1)), true);
+        assert_eq!(sudoku.cells[1][3].contains_possibility(1), true);
+    }
 }
@@ -5,14 +5,13 @@ use itertools::Itertools;
 pub(crate) struct EliminatePossibilitiesUsingXWing;
 
 impl EliminatePossibilitiesUsingXWing {
-    fn in_rows(sudoku: &mut SudokuTemplate) -> bool {
+    pub(crate) fn in_rows(sudoku: &mut SudokuTemplate) -> bool {
         let mut made_changes = false;
 
         // Find the first row and the value of the wing (row where the value is a candidate in only two cells).
         for first_row in 0..9 {
             for value in sudoku.get_missing_values_in_row(first_row) {
                 let columns = (0..9)
-                    .into_iter()
                     .filter(|&col| sudoku.cells[first_row][col].contains_possibility(value))
                     .collect_vec();
                 if columns.len() != 2 {
@@ -26,7 +25,6 @@ impl EliminatePossibilitiesUsingXWing {
                         continue;
                     }
                     let columns_in_second_row = (0..9)
-                        .into_iter()
                         .filter(|&col| sudoku.cells[second_row][col].contains_possibility(value))
                         .collect_vec();
                     if columns != columns_in_second_row {
@@ -42,7 +40,7 @@ impl EliminatePossibilitiesUsingXWing {
                             continue;
                         }
 
-                        let &first_column = columns.get(0).unwrap();
+                        let &first_column = columns.first().unwrap();
                         let &second_column = columns.get(1).unwrap();
 
                         made_changes |= sudoku.cells[other_row][first_column].remove_possibility(value);
@@ -64,4 +62,8 @@ impl SudokuSolvingStrategy for EliminatePossibilitiesUsingXWing {
     fn difficulty(&self) -> Difficulty {
         Difficulty::Hard
     }
+
+    fn name(&self) -> &'static str {
+        "X-Wing"
+    }
 }
\ No newline at end of file
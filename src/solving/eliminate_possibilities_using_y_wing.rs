@@ -1,5 +1,5 @@
 use crate::solving::traits::{Difficulty, SudokuSolvingStrategy};
-use crate::traits::SudokuTemplate;
+use crate::traits::{Sudoku, SudokuTemplate};
 use itertools::iproduct;
 
 pub(crate) struct EliminatePossibilitiesUsingYWing;
@@ -17,7 +17,7 @@ impl EliminatePossibilitiesUsingYWing {
             // Find the second wing (cell that is unrelated to the first wing, has only two possible values and has
             // exactly one common possible value with the first wing).
             for second_wing in iproduct!(0..9, 0..9) {
-                if Self::are_cells_related(first_wing, second_wing) {
+                if Sudoku::cells_see_each_other(first_wing, second_wing) {
                     continue;
                 }
                 let second_wing_possible_values = sudoku.cells[second_wing.0][second_wing.1].possible_values();
@@ -38,7 +38,8 @@ impl EliminatePossibilitiesUsingYWing {
                 // Find the middle (cell that is related to both wings, has only two possible values and the possible
                 // values are the distinct candidates of the wings).
                 for middle in iproduct!(0..9, 0..9) {
-                    if !Self::are_cells_related(first_wing, middle) || !Self::are_cells_related(second_wing, middle) {
+                    if !Sudoku::cells_see_each_other(first_wing, middle) ||
+                        !Sudoku::cells_see_each_other(second_wing, middle) {
                         continue;
                     }
                     let middle_possible_values = sudoku.cells[middle.0][middle.1].possible_values();
@@ -56,8 +57,8 @@ impl EliminatePossibilitiesUsingYWing {
                     // that the common candidate can be removed as a possibility to all cells that are related to both
                     // wings.
                     for related in iproduct!(0..9, 0..9) {
-                        if !Self::are_cells_related(first_wing, related) ||
-                            !Self::are_cells_related(second_wing, related) {
+                        if !Sudoku::cells_see_each_other(first_wing, related) ||
+                            !Sudoku::cells_see_each_other(second_wing, related) {
                             continue;
                         }
 
@@ -70,20 +71,14 @@ impl EliminatePossibilitiesUsingYWing {
         made_changes
     }
 
-    fn are_cells_related(first: (usize, usize), second: (usize, usize)) -> bool {
-        first.0 == second.0
-            || first.1 == second.1
-            || (first.0 / 3 == second.0 / 3 && first.1 / 3 == second.1 / 3)
-    }
-
-    fn have_only_one_common_element(first: &Vec<usize>, second: &Vec<usize>) -> bool {
+    fn have_only_one_common_element(first: &[usize], second: &[usize]) -> bool {
         (first[0] == second[0] && first[1] != second[1])
             || (first[0] == second[1] && first[1] != second[0])
             || (first[1] == second[0] && first[0] != second[1])
             || (first[1] == second[1] && first[0] != second[0])
     }
 
-    fn get_common_element(first: &Vec<usize>, second: &Vec<usize>) -> usize {
+    fn get_common_element(first: &[usize], second: &[usize]) -> usize {
         if first[0] == second[0] || first[0] == second[1] {
             first[0]
         } else if first[1] == second[0] || first[1] == second[1] {
@@ -102,4 +97,8 @@ impl SudokuSolvingStrategy for EliminatePossibilitiesUsingYWing {
     fn difficulty(&self) -> Difficulty {
         Difficulty::Hard
     }
+
+    fn name(&self) -> &'static str {
+        "Y-Wing"
+    }
 }
\ No newline at end of file
@@ -0,0 +1,168 @@
+use itertools::iproduct;
+
+use crate::solving::eliminate_possibilities_using_existing_singles::EliminatePossibilitiesUsingExistingSingles;
+use crate::solving::solver::implemented_strategies_without_existing_singles;
+use crate::solving::traits::{Difficulty, SudokuSolvingStrategy};
+use crate::traits::{Sudoku, SudokuTemplate};
+
+/// Finds the empty cell that can be solved using the easiest technique. All cells solvable at the lowest difficulty are
+/// ranked by technique, naked singles first and then the strategies in the order used by the solver, and finally by
+/// position in row-major order. Returns the row, column and value of the cell along with the difficulty needed to solve
+/// it, or `None` if no cell can be solved.
+pub fn easiest_next_cell(sudoku: &Sudoku) -> Option<(usize, usize, usize, Difficulty)> {
+    let original = SudokuTemplate::from(sudoku.clone());
+    let strategies = implemented_strategies_without_existing_singles();
+
+    for difficulty in [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard] {
+        let allowed_strategies = strategies
+            .iter()
+            .filter(|s| s.difficulty() <= difficulty)
+            .map(Box::as_ref)
+            .collect::<Vec<_>>();
+        let mut template = original;
+
+        // Rank the cells solvable from the current candidates, or apply the allowed strategies to reduce them further.
+        loop {
+            if let Some((row, col, value)) = easiest_solvable_cell(&template, &allowed_strategies) {
+                return Some((row, col, value, difficulty));
+            }
+            if !allowed_strategies.iter().any(|s| s.solve(&mut template)) {
+                break;
+            }
+        }
+    }
+
+    None
+}
+
+/// Finds the empty cell of the template that is the easiest to solve, ranking naked singles first, then the cells set
+/// by each strategy when applied to the same reduced candidates, and finally by position in row-major order.
+fn easiest_solvable_cell(
+    template: &SudokuTemplate,
+    strategies: &[&dyn SudokuSolvingStrategy],
+) -> Option<(usize, usize, usize)> {
+    if let Some(naked_single) = first_naked_single(template) {
+        return Some(naked_single);
+    }
+
+    // Without naked singles, eliminating the values of the set cells does not set any cell.
+    let mut reduced = *template;
+    while (EliminatePossibilitiesUsingExistingSingles {}).solve(&mut reduced) {}
+
+    strategies.iter().find_map(|strategy| {
+        let mut attempt = reduced;
+        strategy.solve(&mut attempt);
+        iproduct!(0..9, 0..9)
+            .find(|&(row, col)| reduced.cells[row][col].is_empty() && attempt.cells[row][col].is_set())
+            .map(|(row, col)| (row, col, attempt.cells[row][col].get_value()))
+    })
+}
+
+/// Finds the first empty cell in row-major order with a single possibility left once the values of its peers are
+/// removed, without placing any value.
+fn first_naked_single(template: &SudokuTemplate) -> Option<(usize, usize, usize)> {
+    iproduct!(0..9, 0..9)
+        .filter(|&(row, col)| template.cells[row][col].is_empty())
+        .find_map(|(row, col)| {
+            let mut cell = template.cells[row][col];
+            for (peer_row, peer_col) in Sudoku::peer_cells(row, col) {
+                cell.remove_possibility(template.cells[peer_row][peer_col].get_value());
+            }
+            cell.is_set().then(|| (row, col, cell.get_value()))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solving::hints::easiest_next_cell;
+    use crate::solving::traits::Difficulty;
+    use crate::Sudoku;
+
+    const EMPTY_SUDOKU: &str = "\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+    ";
+
+    // The cell (8, 8) is a naked single with value 9 and the cell (2, 0) is a hidden single with value 1 in its square.
+    const SUDOKU_WITH_NAKED_AND_HIDDEN_SINGLE: &str = "\
+        ....1....\
+        ......1..\
+        ........8\
+        ........7\
+        ........6\
+        ..1.....5\
+        .........\
+        .1.......\
+        ....4321.\
+    ";
+
+    // The cell (2, 0) is a hidden single with value 1 in its square, and the later cell (8, 8) is a naked single with
+    // value 9 that relies on the values of its square.
+    const SUDOKU_WITH_EARLIER_HIDDEN_SINGLE: &str = "\
+        ....1....\
+        ......1..\
+        .........\
+        ........5\
+        ........6\
+        ..1.....7\
+        ......81.\
+        .1.......\
+        ...234...\
+    ";
+
+    // The cell (0, 0) is a hidden single with value 1 in its square.
+    const SUDOKU_WITH_HIDDEN_SINGLE: &str = "\
+        .........\
+        ...1.....\
+        ......1..\
+        .1.......\
+        .........\
+        .........\
+        ..1......\
+        .........\
+        .........\
+    ";
+
+    #[test]
+    fn easiest_next_cell_prefers_naked_single() {
+        // Given a sudoku with both a naked single and a hidden single.
+        let sudoku = SUDOKU_WITH_NAKED_AND_HIDDEN_SINGLE.parse::<Sudoku>().unwrap();
+
+        // When I get the easiest next cell, then the naked single should be returned.
+        assert_eq!(easiest_next_cell(&sudoku), Some((8, 8, 9, Difficulty::Easy)));
+    }
+
+    #[test]
+    fn easiest_next_cell_prefers_naked_single_over_earlier_hidden_single() {
+        // Given a sudoku where a hidden single comes before a naked single in row-major order.
+        let sudoku = SUDOKU_WITH_EARLIER_HIDDEN_SINGLE.parse::<Sudoku>().unwrap();
+
+        // When I get the easiest next cell, then the naked single should still be returned.
+        assert_eq!(easiest_next_cell(&sudoku), Some((8, 8, 9, Difficulty::Easy)));
+    }
+
+    #[test]
+    fn easiest_next_cell_finds_hidden_single() {
+        // Given a sudoku with only a hidden single.
+        let sudoku = SUDOKU_WITH_HIDDEN_SINGLE.parse::<Sudoku>().unwrap();
+
+        // When I get the easiest next cell, then the hidden single should be returned.
+        assert_eq!(easiest_next_cell(&sudoku), Some((0, 0, 1, Difficulty::Easy)));
+    }
+
+    #[test]
+    fn easiest_next_cell_returns_none_when_nothing_can_be_solved() {
+        // Given an empty sudoku.
+        let sudoku = EMPTY_SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I get the easiest next cell, then nothing should be returned.
+        assert_eq!(easiest_next_cell(&sudoku), None);
+    }
+}
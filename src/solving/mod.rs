@@ -1,10 +1,18 @@
+pub mod backtracking;
+pub mod cached_solver;
+pub mod custom_strategy;
+pub mod debug;
+pub mod hints;
 pub mod solver;
 
-mod eliminate_possibilities_using_existing_singles;
+pub(crate) mod eliminate_possibilities_using_existing_singles;
 mod eliminate_possibilities_using_hidden_groups;
 mod eliminate_possibilities_using_naked_pairs;
 mod eliminate_possibilities_using_pointing;
 mod eliminate_possibilities_using_x_wing;
 mod eliminate_possibilities_using_y_wing;
+mod propagate_singles;
+pub mod search_trace;
 mod set_hidden_singles;
-mod traits;
+mod set_last_possible_cell_in_box;
+pub(crate) mod traits;
@@ -0,0 +1,87 @@
+use itertools::iproduct;
+
+use crate::traits::{Sudoku, SudokuTemplate};
+
+/// Removes the value of every set cell from the possibilities of the cells in the same row, column or square, until no
+/// more changes occur. Since a cell is set as soon as only one possibility remains, this also places all naked singles
+/// that appear along the way. Each set cell is only propagated once, which makes this much cheaper than repeatedly
+/// applying the elimination strategies. Returns `true` if any possibility was removed, `false` otherwise.
+pub(crate) fn propagate_singles(sudoku: &mut SudokuTemplate) -> bool {
+    let mut made_changes = false;
+    let mut propagated = [[false; 9]; 9];
+
+    loop {
+        let mut propagated_any = false;
+
+        // For each set cell that has not been propagated yet
+        for (x, y) in iproduct!(0..9, 0..9) {
+            if propagated[x][y] || sudoku.cells[x][y].is_empty() {
+                continue;
+            }
+            propagated[x][y] = true;
+            propagated_any = true;
+
+            // Remove its value as a possibility from the other cells in the same row, column and square
+            let value = sudoku.cells[x][y].get_value();
+            for (px, py) in Sudoku::peer_cells(x, y) {
+                made_changes |= sudoku.cells[px][py].remove_possibility(value);
+            }
+        }
+
+        if !propagated_any {
+            return made_changes;
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use crate::solving::propagate_singles::propagate_singles;
+    use crate::solving::solver::{implemented_strategies, solve};
+    use crate::traits::SudokuTemplate;
+    use crate::Sudoku;
+
+    const SUDOKUS: [&str; 3] = [
+        "...6.94..29..8.....6...5............5......729124675833..17..9.159..2......9...1.",
+        ".....3.......5.1..8.....2..........4.9.....3.2...1......6..........7.81..43..9...",
+        "...........2..7.6493.4..1.....1..2.63..7.4..91.6..3.....8..5.7225.6..9...........",
+    ];
+
+    #[test]
+    fn propagate_singles_places_naked_singles() {
+        // Given a sudoku where the first cell can only contain 9 because of its row and column.
+        let mut sudoku = SudokuTemplate::from("\
+            .1234....\
+            .........\
+            .........\
+            5........\
+            6........\
+            7........\
+            8........\
+            .........\
+            .........\
+        ".parse::<Sudoku>().unwrap());
+
+        // When I propagate the singles, then the first cell should be set to 9.
+        assert_eq!(propagate_singles(&mut sudoku), true);
+        assert_eq!(sudoku.cells[0][0].get_value(), 9);
+
+        // When I propagate the singles again, then nothing should change.
+        assert_eq!(propagate_singles(&mut sudoku), false);
+    }
+
+    #[test]
+    fn solve_results_are_unchanged_by_propagation() {
+        for sudoku in SUDOKUS {
+            // Given a sudoku solved only with the strategies.
+            let mut template = SudokuTemplate::from(sudoku.parse::<Sudoku>().unwrap());
+            let strategies = implemented_strategies();
+            while strategies.iter().any(|s| s.solve(&mut template)) {}
+
+            // When I solve it with the solver, which also propagates singles, then the results should be equal.
+            let solved = solve(&sudoku.parse::<Sudoku>().unwrap());
+            assert_eq!(solved.to_string(), Sudoku::from(template).to_string(), "{sudoku} was solved differently.");
+        }
+    }
+}
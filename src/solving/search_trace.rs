@@ -0,0 +1,163 @@
+use itertools::iproduct;
+
+use crate::solving::solver::solve_template;
+use crate::traits::{Sudoku, SudokuTemplate};
+
+/// Maximum number of nodes in the tree returned by `solve_trace`.
+pub const MAX_SEARCH_NODES: usize = 1000;
+
+/// Node of the search tree of `solve_trace`.
+#[derive(Clone, Debug)]
+pub struct SearchNode {
+    /// The guessed cell and value as `(row, column, value)`, or `None` for the root node.
+    pub guess: Option<(usize, usize, usize)>,
+    /// The grid after applying the guess and solving as far as possible without guessing.
+    pub grid: Sudoku,
+    /// The outcome of the search from this node.
+    pub outcome: SearchOutcome,
+    /// The searches for each guess that was tried from this node, in the order they were tried.
+    pub children: Vec<SearchNode>,
+}
+
+/// Outcome of the search from a `SearchNode`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SearchOutcome {
+    /// The node or one of its children led to the solution.
+    Solution,
+    /// The node led to a contradiction, or all of its children are dead ends.
+    DeadEnd,
+    /// The search was stopped because the tree reached `MAX_SEARCH_NODES` nodes.
+    Truncated,
+}
+
+/// Solves a sudoku, guessing when the strategies get stuck, and returns the search tree. At each node the strategies
+/// are applied first; if they get stuck, the values of the empty cell with the fewest possibilities are guessed in
+/// increasing order until one of them leads to the solution. The tree is limited to `MAX_SEARCH_NODES` nodes.
+pub fn solve_trace(sudoku: &Sudoku) -> SearchNode {
+    let mut node_count = 0;
+    search(SudokuTemplate::from(sudoku.clone()), None, &mut node_count)
+}
+
+fn search(mut template: SudokuTemplate, guess: Option<(usize, usize, usize)>, node_count: &mut usize) -> SearchNode {
+    *node_count += 1;
+    solve_template(&mut template);
+
+    let mut node = SearchNode {
+        guess,
+        grid: Sudoku::from(template),
+        outcome: SearchOutcome::DeadEnd,
+        children: vec![],
+    };
+
+    // A cell without any possibility means that the grid cannot be solved.
+    if iproduct!(0..9, 0..9).any(|(x, y)| template.cells[x][y].possible_values().is_empty()) {
+        return node;
+    }
+
+    // Guess in the empty cell with the fewest possibilities, or stop if there is none.
+    let Some((x, y)) = iproduct!(0..9, 0..9)
+        .filter(|&(x, y)| template.cells[x][y].is_empty())
+        .min_by_key(|&(x, y)| template.cells[x][y].possible_values().len()) else {
+        node.outcome = SearchOutcome::Solution;
+        return node;
+    };
+
+    for value in template.cells[x][y].possible_values() {
+        if *node_count >= MAX_SEARCH_NODES {
+            node.outcome = SearchOutcome::Truncated;
+            return node;
+        }
+
+        let mut guessed = template;
+        guessed.cells[x][y].set_value(value);
+        let child = search(guessed, Some((x, y, value)), node_count);
+        let child_outcome = child.outcome;
+        node.children.push(child);
+
+        if child_outcome != SearchOutcome::DeadEnd {
+            node.outcome = child_outcome;
+            return node;
+        }
+    }
+
+    node
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solving::search_trace::{solve_trace, SearchOutcome};
+    use crate::Sudoku;
+
+    const EASY_SUDOKU: &str = "\
+        ...6.94..\
+        29..8....\
+        .6...5...\
+        .........\
+        5......72\
+        912467583\
+        3..17..9.\
+        159..2...\
+        ...9...1.\
+    ";
+
+    const SUDOKU_REQUIRING_ONE_GUESS: &str = "\
+        8.56...2.\
+        2..7.31.6\
+        7....5839\
+        ..3.2....\
+        .4...1.7.\
+        ....675..\
+        3..1.....\
+        ...8.276.\
+        42.9.....\
+    ";
+
+    const SOLUTION: &str = "\
+        835619427\
+        294783156\
+        761245839\
+        673528941\
+        548391672\
+        912467583\
+        386174295\
+        159832764\
+        427956318\
+    ";
+
+    #[test]
+    fn solve_trace_has_no_branches_without_guessing() {
+        // Given a sudoku that can be solved without guessing.
+        let sudoku = EASY_SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I trace its solving, then the root should be solved without any children.
+        let root = solve_trace(&sudoku);
+        assert_eq!(root.outcome, SearchOutcome::Solution);
+        assert_eq!(root.guess, None);
+        assert_eq!(root.children.len(), 0);
+        assert_eq!(root.grid.to_string(), SOLUTION);
+    }
+
+    #[test]
+    fn solve_trace_records_dead_end_and_solution_branches() {
+        // Given a sudoku that requires a single guess between two values.
+        let sudoku = SUDOKU_REQUIRING_ONE_GUESS.parse::<Sudoku>().unwrap();
+
+        // When I trace its solving.
+        let root = solve_trace(&sudoku);
+
+        // Then the root should have a dead end branch followed by the solution branch, both without children.
+        assert_eq!(root.outcome, SearchOutcome::Solution);
+        assert_eq!(root.children.len(), 2);
+
+        let dead_end = &root.children[0];
+        assert_eq!(dead_end.guess, Some((2, 1, 1)));
+        assert_eq!(dead_end.outcome, SearchOutcome::DeadEnd);
+        assert_eq!(dead_end.children.len(), 0);
+
+        let solution = &root.children[1];
+        assert_eq!(solution.guess, Some((2, 1, 6)));
+        assert_eq!(solution.outcome, SearchOutcome::Solution);
+        assert_eq!(solution.children.len(), 0);
+        assert_eq!(solution.grid.to_string(), SOLUTION);
+    }
+}
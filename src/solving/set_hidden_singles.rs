@@ -31,13 +31,14 @@ use crate::traits::SudokuTemplate;
 ///
 /// This strategy implementation iterates through all possible values of all empty cells in the sudoku. For each
 /// possible value, it checks weather the value is also possible in other related empty cells. If not, it then sets the
-/// value to the only empty cell that can contain it.
+/// value to the only empty cell that can contain it. Only rows and columns are considered when solving, as hidden
+/// singles in squares are set by `SetLastPossibleCellInBox`.
 pub(crate) struct SetHiddenSingles;
 
 impl SetHiddenSingles {
     /// For each possible value of each empty cell, it sets the value to the cell if the value is only possible in the
     /// cell and not in other empty cells in the same row or column.
-    fn in_rows_and_columns(sudoku: &mut SudokuTemplate) -> bool {
+    pub(crate) fn in_rows_and_columns(sudoku: &mut SudokuTemplate) -> bool {
         let mut made_changes = false;
 
         // For each cell
@@ -87,7 +88,7 @@ impl SetHiddenSingles {
 
     /// For each square, for each possible value of each empty cell in the square, it sets the value to the cell if
     /// the value is only possible in the cell and not in other empty cells in the same square.
-    fn in_squares(sudoku: &mut SudokuTemplate) -> bool {
+    pub(crate) fn in_squares(sudoku: &mut SudokuTemplate) -> bool {
         let mut made_changes = false;
 
         // For each square
@@ -128,15 +129,20 @@ impl SetHiddenSingles {
 
 impl SudokuSolvingStrategy for SetHiddenSingles {
     fn solve(&self, sudoku: &mut SudokuTemplate) -> bool {
-        SetHiddenSingles::in_rows_and_columns(sudoku) || SetHiddenSingles::in_squares(sudoku)
+        SetHiddenSingles::in_rows_and_columns(sudoku)
     }
 
     fn difficulty(&self) -> Difficulty {
         Difficulty::Easy
     }
+
+    fn name(&self) -> &'static str {
+        "Hidden singles"
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison, clippy::clone_on_copy)]
 mod tests {
     use crate::solving::set_hidden_singles::SetHiddenSingles;
     use crate::solving::traits::{Difficulty, SudokuSolvingStrategy};
@@ -290,10 +296,11 @@ mod tests {
         let mut sudoku2 = SudokuTemplate::from(SUDOKU_WITH_HIDDEN_SINGLE_IN_COLUMN.parse::<Sudoku>().unwrap());
         let mut sudoku3 = SudokuTemplate::from(SUDOKU_WITH_HIDDEN_SINGLE_IN_SQUARE.parse::<Sudoku>().unwrap());
 
-        // When I apply the strategy using solve(), then it should return true.
+        // When I apply the strategy using solve(), then it should return true for the rows and columns, while squares
+        // are left to `SetLastPossibleCellInBox`.
         assert_eq!(SetHiddenSingles {}.solve(&mut sudoku1), true);
         assert_eq!(SetHiddenSingles {}.solve(&mut sudoku2), true);
-        assert_eq!(SetHiddenSingles {}.solve(&mut sudoku3), true);
+        assert_eq!(SetHiddenSingles {}.solve(&mut sudoku3), false);
 
         // Given a sudoku without hidden singles.
         let mut sudoku4 = SudokuTemplate::from(SUDOKU_WITHOUT_HIDDEN_SINGLES.parse::<Sudoku>().unwrap());
@@ -0,0 +1,84 @@
+use crate::solving::set_hidden_singles::SetHiddenSingles;
+use crate::solving::traits::{Difficulty, SudokuSolvingStrategy};
+use crate::traits::SudokuTemplate;
+
+/// Sudoku strategy that sets hidden singles in squares, i.e. values that are possible in only one empty cell of a
+/// square. It complements `SetHiddenSingles`, which only looks at rows and columns, so that placements relying only on
+/// squares can be told apart from placements relying on rows and columns.
+///
+/// Consider the following example:
+/// ```text
+///    1 2 3 4 5 6 7 8 9
+///    -----------------
+/// A |  2 3|     |     |
+/// B |     |    1|     |
+/// C |4 5  |     |     |
+///    -----------------
+/// D |     |     |     |
+/// E |     |     |     |
+/// F |     |     |     |
+///    -----------------
+/// G |     |     |     |
+/// H |    1|     |     |
+/// I |     |     |     |
+///    -----------------
+/// ```
+/// In block `A-C, 1-3`, the value 1 cannot be in row `B` because of `B6`, and cannot be in `C3` because of `H3`. This
+/// leaves `A1` as the last possible cell for the value 1 in the block, although neither row `A` nor column `1` force it.
+pub(crate) struct SetLastPossibleCellInBox;
+
+impl SudokuSolvingStrategy for SetLastPossibleCellInBox {
+    fn solve(&self, sudoku: &mut SudokuTemplate) -> bool {
+        SetHiddenSingles::in_squares(sudoku)
+    }
+
+    fn difficulty(&self) -> Difficulty {
+        Difficulty::Easy
+    }
+
+    fn name(&self) -> &'static str {
+        "Last possible cell in box"
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use crate::solving::eliminate_possibilities_using_existing_singles::EliminatePossibilitiesUsingExistingSingles;
+    use crate::solving::set_hidden_singles::SetHiddenSingles;
+    use crate::solving::set_last_possible_cell_in_box::SetLastPossibleCellInBox;
+    use crate::solving::traits::{Difficulty, SudokuSolvingStrategy};
+    use crate::traits::SudokuTemplate;
+    use crate::Sudoku;
+
+    const SUDOKU_WITH_HIDDEN_SINGLE_ONLY_IN_BOX: &str = "\
+        .23......\
+        .....1...\
+        45.......\
+        .........\
+        .........\
+        .........\
+        .........\
+        ..1......\
+        .........\
+    ";
+
+    #[test]
+    fn solve_sets_value_forced_only_by_box() {
+        // Given a sudoku where 1 can only be in the first cell of the first square, but not only in its row or column.
+        let mut sudoku = SudokuTemplate::from(SUDOKU_WITH_HIDDEN_SINGLE_ONLY_IN_BOX.parse::<Sudoku>().unwrap());
+        while (EliminatePossibilitiesUsingExistingSingles {}).solve(&mut sudoku) {}
+
+        // When I look for hidden singles in rows and columns, then nothing should be found.
+        assert_eq!(SetHiddenSingles::in_rows_and_columns(&mut sudoku), false);
+
+        // When I apply the strategy, then the value should be set.
+        assert_eq!(SetLastPossibleCellInBox {}.solve(&mut sudoku), true);
+        assert_eq!(sudoku.cells[0][0].get_value(), 1);
+    }
+
+    #[test]
+    fn difficulty_is_easy() {
+        assert_eq!(SetLastPossibleCellInBox {}.difficulty(), Difficulty::Easy);
+    }
+}
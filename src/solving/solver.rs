@@ -1,18 +1,143 @@
+use crate::solving::custom_strategy::{CustomStrategy, PublicStrategy};
 use crate::solving::eliminate_possibilities_using_existing_singles::EliminatePossibilitiesUsingExistingSingles;
 use crate::solving::eliminate_possibilities_using_hidden_groups::EliminatePossibilitiesUsingHiddenCombinationsGroups;
 use crate::solving::eliminate_possibilities_using_naked_pairs::EliminatePossibilitiesUsingNakedPairs;
 use crate::solving::eliminate_possibilities_using_pointing::EliminatePossibilitiesUsingPointing;
 use crate::solving::eliminate_possibilities_using_x_wing::EliminatePossibilitiesUsingXWing;
 use crate::solving::eliminate_possibilities_using_y_wing::EliminatePossibilitiesUsingYWing;
+use crate::solving::propagate_singles::propagate_singles;
 use crate::solving::set_hidden_singles::SetHiddenSingles;
-use crate::solving::traits::SudokuSolvingStrategy;
+use crate::solving::set_last_possible_cell_in_box::SetLastPossibleCellInBox;
+use crate::solving::traits::{Difficulty, SudokuSolvingStrategy};
 use crate::traits::Sudoku;
-use crate::traits::SudokuTemplate;
+use crate::traits::{count_candidate_inspections, SudokuTemplate};
 
 pub fn solve(sudoku: &Sudoku) -> Sudoku {
     let mut template = SudokuTemplate::from(sudoku.clone());
+    solve_template(&mut template);
+    Sudoku::from(template)
+}
+
+/// Solves a sudoku starting from the provided candidates of each cell instead of a grid, e.g. to continue from
+/// candidates computed elsewhere or to inject additional constraints. Cells with a single candidate are considered
+/// set to that candidate.
+pub fn solve_from_candidates(candidates: &[[Vec<usize>; 9]; 9]) -> Sudoku {
+    let mut template = SudokuTemplate::from(candidates);
+    solve_template(&mut template);
+    Sudoku::from(template)
+}
+
+/// Solves a sudoku starting from candidates that are already reduced, i.e. in which no cell has a candidate that is
+/// the only candidate of another cell in the same row, column or square. Cells with a single candidate are considered
+/// set to that candidate. The elimination of the values of set cells is only applied after the other strategies made
+/// changes, so the candidates must be valid and reduced: otherwise, the returned sudoku may be wrong or incomplete.
+pub fn solve_assuming_reduced(template_candidates: &[[Vec<usize>; 9]; 9]) -> Sudoku {
+    let mut template = SudokuTemplate::from(template_candidates);
+    let strategies = implemented_strategies_without_existing_singles();
 
-    let strategies: Vec<Box<dyn SudokuSolvingStrategy>> = vec![
+    // Propagate singles only after the strategies made changes, as the candidates are already reduced.
+    while strategies.iter().any(|s| s.solve(&mut template)) {
+        propagate_singles(&mut template);
+    }
+    Sudoku::from(template)
+}
+
+/// Solves a sudoku in which the provided candidates, as `(row, column, value)` tuples, are forbidden, e.g. to explore
+/// what happens if a cell cannot hold a value. Exclusions in filled cells or outside the grid are ignored, as are values
+/// that are not between 1 and 9. If an exclusion contradicts the only solution, the returned sudoku is not solved.
+pub fn solve_with_exclusions(sudoku: &Sudoku, exclusions: &[(usize, usize, usize)]) -> Sudoku {
+    let mut template = SudokuTemplate::from(sudoku.clone());
+    for &(row, column, value) in exclusions {
+        if row < 9 && column < 9 && sudoku.get_cells()[row][column] == 0 {
+            template.cells[row][column].remove_possibility(value);
+        }
+    }
+    solve_template(&mut template);
+    Sudoku::from(template)
+}
+
+/// Solves a sudoku by applying the provided custom strategies after the implemented ones.
+pub fn solve_with_custom(sudoku: &Sudoku, extra: Vec<Box<dyn PublicStrategy>>) -> Sudoku {
+    let mut strategies = implemented_strategies();
+    for strategy in extra {
+        strategies.push(Box::new(CustomStrategy(strategy)));
+    }
+
+    let mut template = SudokuTemplate::from(sudoku.clone());
+    solve_template_with(&mut template, &strategies);
+    Sudoku::from(template)
+}
+
+/// Counts the steps needed to solve a sudoku logically, where a step is either a propagation of the set values to the
+/// candidates of their peers or the application of a strategy that made changes. Returns `None` if the sudoku cannot
+/// be solved using the implemented strategies.
+pub fn solve_step_count(sudoku: &Sudoku) -> Option<usize> {
+    let mut template = SudokuTemplate::from(sudoku.clone());
+    let steps = solve_template_with(&mut template, &implemented_strategies());
+
+    if template.cells.iter().flatten().all(|cell| cell.is_set()) {
+        Some(steps)
+    } else {
+        None
+    }
+}
+
+/// Solves a sudoku like `solve`, and counts the inspections of the candidates of any cell performed while solving. The
+/// count only depends on the sudoku and the implemented strategies, which makes it a machine-independent measure of
+/// the solving work, e.g. to catch performance regressions in tests. Inspections are only counted while this function
+/// runs, so the other solving functions are not slowed down by the counting.
+pub fn solve_op_count(sudoku: &Sudoku) -> (Sudoku, u64) {
+    let mut template = SudokuTemplate::from(sudoku.clone());
+    let ((), inspections) = count_candidate_inspections(|| solve_template(&mut template));
+    (Sudoku::from(template), inspections)
+}
+
+/// Solves a sudoku tier by tier, first using only the easy strategies, then also the medium ones and finally all of
+/// them. Returns the grid reached after exhausting each tier, e.g. to reveal the solving progress by skill level.
+pub fn solve_by_tiers(sudoku: &Sudoku) -> Vec<(Difficulty, Sudoku)> {
+    let mut template = SudokuTemplate::from(sudoku.clone());
+
+    [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard]
+        .into_iter()
+        .map(|tier| {
+            let strategies = implemented_strategies()
+                .into_iter()
+                .filter(|s| s.difficulty() <= tier)
+                .collect::<Vec<_>>();
+            solve_template_with(&mut template, &strategies);
+            (tier, Sudoku::from(template))
+        })
+        .collect()
+}
+
+/// Applies all implemented strategies to the template until none of them makes any more changes.
+pub(crate) fn solve_template(template: &mut SudokuTemplate) {
+    solve_template_with(template, &implemented_strategies());
+}
+
+/// Applies the provided strategies to the template until none of them makes any more changes. Returns the number of
+/// steps that made changes, where a step is either a propagation of singles or the application of a strategy.
+fn solve_template_with(template: &mut SudokuTemplate, strategies: &[Box<dyn SudokuSolvingStrategy>]) -> usize {
+    let mut steps = 0;
+
+    // Cheaply propagate singles before each round, so the strategies are applied less often.
+    loop {
+        if propagate_singles(template) {
+            steps += 1;
+        }
+        if !strategies.iter().any(|s| s.solve(template)) {
+            break;
+        }
+        steps += 1;
+    }
+
+    steps
+}
+
+/// Provides all implemented strategies, in the order in which they are applied when solving.
+pub(crate) fn implemented_strategies() -> Vec<Box<dyn SudokuSolvingStrategy>> {
+    vec![
+        Box::new(SetLastPossibleCellInBox {}),
         Box::new(SetHiddenSingles {}),
         Box::new(EliminatePossibilitiesUsingExistingSingles {}),
         Box::new(EliminatePossibilitiesUsingPointing {}),
@@ -20,9 +145,34 @@ pub fn solve(sudoku: &Sudoku) -> Sudoku {
         Box::new(EliminatePossibilitiesUsingHiddenCombinationsGroups {}),
         Box::new(EliminatePossibilitiesUsingXWing {}),
         Box::new(EliminatePossibilitiesUsingYWing {}),
-    ];
+    ]
+}
+
+/// Provides all implemented strategies except the elimination of the values of set cells, in the order in which they
+/// are applied when solving.
+pub(crate) fn implemented_strategies_without_existing_singles() -> Vec<Box<dyn SudokuSolvingStrategy>> {
+    vec![
+        Box::new(SetLastPossibleCellInBox {}),
+        Box::new(SetHiddenSingles {}),
+        Box::new(EliminatePossibilitiesUsingPointing {}),
+        Box::new(EliminatePossibilitiesUsingNakedPairs {}),
+        Box::new(EliminatePossibilitiesUsingHiddenCombinationsGroups {}),
+        Box::new(EliminatePossibilitiesUsingXWing {}),
+        Box::new(EliminatePossibilitiesUsingYWing {}),
+    ]
+}
+
+/// Solves a grid as a Latin square, i.e. only enforcing that each value appears once in every row and column. All
+/// logic based on squares is skipped, so values repeating inside a square are not considered a conflict.
+pub fn solve_latin_square(grid: &Sudoku) -> Sudoku {
+    let mut template = SudokuTemplate::from(grid.clone());
 
-    while strategies.iter().any(|s| s.solve(&mut template)) {}
+    while SetHiddenSingles::in_rows_and_columns(&mut template)
+        || EliminatePossibilitiesUsingExistingSingles::in_rows_and_columns(&mut template)
+        || EliminatePossibilitiesUsingNakedPairs::in_rows_and_columns(&mut template)
+        || EliminatePossibilitiesUsingHiddenCombinationsGroups::in_rows(&mut template)
+        || EliminatePossibilitiesUsingHiddenCombinationsGroups::in_columns(&mut template)
+        || EliminatePossibilitiesUsingXWing::in_rows(&mut template) {}
 
     Sudoku::from(template)
 }
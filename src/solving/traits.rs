@@ -21,11 +21,18 @@ pub(crate) trait SudokuSolvingStrategy {
     ///
     /// `Difficulty` - the difficulty level of the strategy.
     fn difficulty(&self) -> Difficulty;
+
+    /// Provides the name of the implemented strategy, e.g. for reporting which strategies were used.
+    ///
+    /// # Returns
+    ///
+    /// `&'static str` - the human readable name of the strategy.
+    fn name(&self) -> &'static str;
 }
 
 /// Difficulty levels of sudoku solving strategies.
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub(crate) enum Difficulty {
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Difficulty {
     Easy,
     Medium,
     Hard,
@@ -0,0 +1,134 @@
+use itertools::iproduct;
+
+use crate::candidates::reduced_template;
+use crate::traits::Sudoku;
+
+/// Options for rendering a sudoku as SVG.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SvgOptions {
+    /// Width and height of a cell in pixels.
+    pub cell_size: usize,
+    /// Whether to render the candidates of the empty cells, as computed by easy elimination.
+    pub show_candidates: bool,
+}
+
+impl Default for SvgOptions {
+    fn default() -> SvgOptions {
+        SvgOptions {
+            cell_size: 50,
+            show_candidates: false,
+        }
+    }
+}
+
+impl Sudoku {
+    /// Renders the sudoku as an SVG document, with thick borders around the squares.
+    pub fn to_svg(&self, opts: SvgOptions) -> String {
+        let cell = opts.cell_size;
+        let size = 9 * cell;
+        let mut svg = vec![
+            format!(r#"<svg xmlns="http://www.w3.org/2000/svg" width="{size}" height="{size}" viewBox="0 0 {size} {size}">"#),
+            format!(r#"<rect x="0" y="0" width="{size}" height="{size}" fill="white"/>"#),
+        ];
+
+        // Grid lines, thicker around the squares.
+        for i in 0..=9 {
+            let position = i * cell;
+            let width = if i % 3 == 0 { 3 } else { 1 };
+            svg.push(format!(
+                r#"<line x1="{position}" y1="0" x2="{position}" y2="{size}" stroke="black" stroke-width="{width}"/>"#
+            ));
+            svg.push(format!(
+                r#"<line x1="0" y1="{position}" x2="{size}" y2="{position}" stroke="black" stroke-width="{width}"/>"#
+            ));
+        }
+
+        // Values, centered in their cells.
+        for (row, col) in iproduct!(0..9, 0..9) {
+            let value = self.get_cells()[row][col];
+            if value != 0 {
+                svg.push(text(col * cell + cell / 2, row * cell + cell / 2, cell * 3 / 5, value));
+            }
+        }
+
+        // Candidates, each in its own position of a 3x3 grid inside the cell.
+        if opts.show_candidates {
+            let template = reduced_template(self);
+            for (row, col) in iproduct!(0..9, 0..9) {
+                if self.get_cells()[row][col] != 0 {
+                    continue;
+                }
+                for value in template.cells[row][col].possible_values() {
+                    let x = col * cell + ((value - 1) % 3) * cell / 3 + cell / 6;
+                    let y = row * cell + ((value - 1) / 3) * cell / 3 + cell / 6;
+                    svg.push(text(x, y, cell / 4, value));
+                }
+            }
+        }
+
+        svg.push(String::from("</svg>"));
+        svg.join("\n")
+    }
+}
+
+fn text(x: usize, y: usize, font_size: usize, value: usize) -> String {
+    format!(
+        r#"<text x="{x}" y="{y}" font-size="{font_size}" text-anchor="middle" dominant-baseline="central">{value}</text>"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::svg::SvgOptions;
+    use crate::Sudoku;
+
+    const SUDOKU: &str = "\
+        123456789\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+        .........\
+        ........1\
+    ";
+
+    #[test]
+    fn to_svg_renders_values() {
+        // Given a sudoku with 10 values.
+        let sudoku = SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I render it as SVG.
+        let svg = sudoku.to_svg(SvgOptions { cell_size: 40, show_candidates: false });
+
+        // Then it should be a complete SVG document with a text element for each value.
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="360" height="360""#));
+        assert!(svg.ends_with("</svg>"));
+        assert_eq!(svg.matches("<text").count(), 10);
+        assert_eq!(svg.matches("<text").count(), svg.matches("</text>").count());
+        assert_eq!(svg.matches("<line").count(), 20);
+    }
+
+    #[test]
+    fn to_svg_renders_candidates() {
+        // Given an empty sudoku and a solved sudoku with two removed values.
+        let empty = ".".repeat(81).parse::<Sudoku>().unwrap();
+        let almost_solved = "\
+            .35619427\
+            294783156\
+            761245839\
+            673528941\
+            548391672\
+            912467583\
+            386174295\
+            159832764\
+            42795631.\
+        ".parse::<Sudoku>().unwrap();
+        let options = SvgOptions { show_candidates: true, ..SvgOptions::default() };
+
+        // When I render them as SVG with candidates, then there should also be a text element for each candidate.
+        assert_eq!(empty.to_svg(options).matches("<text").count(), 81 * 9);
+        assert_eq!(almost_solved.to_svg(options).matches("<text").count(), 79 + 2);
+    }
+}
@@ -1,5 +1,31 @@
+use crate::traits::SudokuTemplate;
 use crate::utils::BoolIteratorUtils;
 
+thread_local! {
+    /// Number of times the possibilities of any cell were inspected on the current thread while counting, or `None` if
+    /// the inspections are not being counted.
+    static CANDIDATE_INSPECTIONS: std::cell::Cell<Option<u64>> = const { std::cell::Cell::new(None) };
+}
+
+/// Runs the provided function and provides the number of times the possibilities of any cell were inspected by it on
+/// the current thread, e.g. to measure the work done by the strategies independently of the machine. Inspections are
+/// only counted while such a function runs.
+pub(crate) fn count_candidate_inspections<T>(f: impl FnOnce() -> T) -> (T, u64) {
+    let previous = CANDIDATE_INSPECTIONS.with(|count| count.replace(Some(0)));
+    let result = f();
+    let inspections = CANDIDATE_INSPECTIONS.with(|count| count.get()).unwrap_or(0);
+    CANDIDATE_INSPECTIONS.with(|count| count.set(previous.map(|previous| previous + inspections)));
+    (result, inspections)
+}
+
+fn count_inspection() {
+    CANDIDATE_INSPECTIONS.with(|count| {
+        if let Some(inspections) = count.get() {
+            count.set(Some(inspections + 1));
+        }
+    });
+}
+
 /// Represents a modifiable sudoku cell.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub(crate) struct Cell {
@@ -19,6 +45,25 @@ impl Cell {
         }
     }
 
+    /// Creates a cell with the provided possible values. If there is only one possible value, it is set as the value of
+    /// the cell. Values that are not between 1 and 9 are ignored.
+    pub(crate) fn from_possibilities(possibilities: &[usize]) -> Cell {
+        let possibilities: [bool; 9] = std::array::from_fn(|i| possibilities.contains(&(i + 1)));
+        let mut cell = Cell { value: 0, possibilities };
+        cell.value = cell.single_possibility().unwrap_or(0);
+        cell
+    }
+
+    /// Provides the only possible value of the cell, or `None` if there is not exactly one. This is not counted as an
+    /// inspection, as it is only used to keep the value of the cell consistent with its possibilities.
+    fn single_possibility(&self) -> Option<usize> {
+        let mut possible_values = (1..=9).filter(|i| self.possibilities[i - 1]);
+        match (possible_values.next(), possible_values.next()) {
+            (Some(value), None) => Some(value),
+            _ => None,
+        }
+    }
+
     fn gen_possibilities(value: usize) -> [bool; 9] {
         (1..=9)
             .map(|i| value == 0 || i == value)
@@ -44,18 +89,29 @@ impl Cell {
 
     /// Provides the possible values that can be set.
     pub(crate) fn possible_values(&self) -> Vec<usize> {
+        count_inspection();
         (1..=9)
             .filter(|i| self.possibilities[i - 1])
             .collect()
     }
 
     pub(crate) fn contains_possibility(&self, possibility: usize) -> bool {
+        count_inspection();
         self.possibilities[possibility - 1]
     }
 
-    pub(crate) fn contains_any_possibilities(&self, possibilities: &[usize]) -> bool {
-        possibilities
-            .iter()
+    /// Provides the possible values as a mask, where bit `n` is set if the value `n` is possible.
+    pub(crate) fn possibilities_mask(&self) -> u16 {
+        count_inspection();
+        (1..=9)
+            .filter(|i| self.possibilities[i - 1])
+            .fold(0, |mask, i| mask | 1 << i)
+    }
+
+    /// Returns `true` if any of the values whose bits are set in the mask is possible, `false` otherwise.
+    pub(crate) fn contains_any_possibilities(&self, possibilities: u16) -> bool {
+        count_inspection();
+        SudokuTemplate::mask_values(possibilities)
             .map(|value| self.possibilities[value - 1])
             .any_true()
     }
@@ -64,8 +120,9 @@ impl Cell {
     /// be set as the cell's value. Returns `true` if the cell state changed as a result of this operation, or `false`
     /// otherwise.
     pub(crate) fn remove_possibility(&mut self, value: usize) -> bool {
+        count_inspection();
         // Check if the value is valid and still possible.
-        if value < 1 || value > 9 || !self.possibilities[value - 1] {
+        if !(1..=9).contains(&value) || !self.possibilities[value - 1] {
             return false;
         }
 
@@ -73,13 +130,13 @@ impl Cell {
         self.possibilities[value - 1] = false;
 
         // Check if only one possible value remains.
-        let remaining_possibilities = self.possible_values();
-        if remaining_possibilities.len() == 1 {
-            self.value = remaining_possibilities[0];
+        if let Some(value) = self.single_possibility() {
+            self.value = value;
         }
         true
     }
 
+    #[allow(dead_code)]
     pub(crate) fn remove_possibilities(&mut self, possibilities: &[usize]) -> bool {
         possibilities
             .iter()
@@ -87,9 +144,11 @@ impl Cell {
             .any_true_exhaustive()
     }
 
-    pub(crate) fn remove_possibilities_outside_of(&mut self, possibilities: &[usize]) -> bool {
+    /// Removes the possible values whose bits are not set in the mask. Returns `true` if the cell state changed as a
+    /// result of this operation, or `false` otherwise.
+    pub(crate) fn remove_possibilities_outside_of(&mut self, possibilities: u16) -> bool {
         (1..=9)
-            .filter(|n| !possibilities.contains(n))
+            .filter(|n| possibilities & 1 << n == 0)
             .map(|n| self.remove_possibility(n))
             .any_true_exhaustive()
     }
@@ -99,7 +158,7 @@ impl Cell {
     /// result of this operation, or `false` otherwise.
     pub(crate) fn set_value(&mut self, value: usize) -> bool {
         // Check if the new value is valid and different from the current value.
-        if value < 1 || value > 9 || value == self.value {
+        if !(1..=9).contains(&value) || value == self.value {
             return false;
         }
 
@@ -109,3 +168,34 @@ impl Cell {
         true
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use crate::traits::cell::{count_candidate_inspections, Cell};
+
+    #[test]
+    fn remove_possibility_counts_as_single_inspection() {
+        // Given an empty cell with only two possibilities left.
+        let mut cell = Cell::from_possibilities(&[4, 7]);
+
+        // When I count the inspections while removing one of them.
+        let (changed, inspections) = count_candidate_inspections(|| cell.remove_possibility(4));
+
+        // Then the removal should count once, and the remaining possibility should be set as value.
+        assert_eq!(changed, true);
+        assert_eq!(inspections, 1);
+        assert_eq!(cell.get_value(), 7);
+    }
+
+    #[test]
+    fn inspections_are_only_counted_while_counting() {
+        // Given an inspection performed outside of any counting.
+        let cell = Cell::new(0);
+        cell.possible_values();
+
+        // When I count the inspections of a function that does not inspect anything, then none should be counted.
+        let ((), inspections) = count_candidate_inspections(|| ());
+        assert_eq!(inspections, 0);
+    }
+}
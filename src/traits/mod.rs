@@ -1,7 +1,10 @@
-pub use sudoku::{Sudoku, SudokuStrParsingError};
+pub use sudoku::{Sudoku, SudokuAlphaParsingError, SudokuJsonParsingError, SudokuStrParsingError};
+pub use unit::Unit;
 
-pub(crate) use sudoku_template::SudokuTemplate;
+pub(crate) use cell::count_candidate_inspections;
+pub(crate) use sudoku_template::{SudokuTemplate, ALL_DIGITS};
 
 mod cell;
 mod sudoku;
 mod sudoku_template;
+mod unit;
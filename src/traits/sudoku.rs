@@ -1,8 +1,12 @@
 use std::error::Error;
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
-use crate::traits::SudokuTemplate;
+use itertools::{iproduct, Itertools};
+
+use crate::candidates::reduced_template;
+use crate::traits::{SudokuTemplate, Unit};
 
 /// Represents a Sudoku puzzle. Empty cells should be set as zero.
 #[derive(Clone, Debug)]
@@ -19,6 +23,189 @@ impl Sudoku {
     pub fn get_cells(&self) -> &[[usize; 9]; 9] {
         &self.cells
     }
+
+    /// Returns `true` if two different cells share a row, column or square, `false` otherwise. Cells are provided as
+    /// `(row, column)` coordinates.
+    pub fn cells_see_each_other(a: (usize, usize), b: (usize, usize)) -> bool {
+        a != b && (a.0 == b.0 || a.1 == b.1 || (a.0 / 3 == b.0 / 3 && a.1 / 3 == b.1 / 3))
+    }
+
+    /// Provides the region id of every cell for the standard 3x3 squares, where squares are numbered from 0 to 8 in
+    /// row-major order, e.g. as a starting point for a custom layout of regions.
+    pub fn standard_regions() -> [[usize; 9]; 9] {
+        std::array::from_fn(|row| std::array::from_fn(|col| 3 * (row / 3) + col / 3))
+    }
+
+    /// Provides the 20 cells that share a row, column or square with `(row, col)`, excluding the cell itself, in
+    /// row-major order.
+    pub fn peers(&self, row: usize, col: usize) -> Vec<(usize, usize)> {
+        Sudoku::peer_cells(row, col).collect_vec()
+    }
+
+    /// Provides the cells that share a row, column or square with `(row, col)` like `peers`, without allocating them or
+    /// requiring a sudoku.
+    pub(crate) fn peer_cells(row: usize, col: usize) -> impl Iterator<Item=(usize, usize)> {
+        iproduct!(0..9, 0..9).filter(move |&cell| Sudoku::cells_see_each_other((row, col), cell))
+    }
+
+    /// Provides a hash of the cells of the sudoku. Equal sudokus always have equal fingerprints. The value is only
+    /// meant to be used within the same process, as it is not guaranteed to be stable across Rust versions.
+    pub fn fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.cells.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Provides the coordinates of all cells that contain `digit`, in row-major order. Returns an empty vector if
+    /// `digit` is not between 1 and 9.
+    pub fn positions_of(&self, digit: usize) -> Vec<(usize, usize)> {
+        if !(1..=9).contains(&digit) {
+            return vec![];
+        }
+
+        iproduct!(0..9, 0..9)
+            .filter(|&(row, col)| self.cells[row][col] == digit)
+            .collect_vec()
+    }
+
+    /// Provides the cells in the same row, column or square as `(row, col)` that hold the same value, in row-major
+    /// order, e.g. to highlight the conflicts caused by an edit. Returns an empty vector if the cell is empty or outside
+    /// the grid.
+    pub fn placement_conflicts(&self, row: usize, col: usize) -> Vec<(usize, usize)> {
+        if row >= 9 || col >= 9 {
+            return vec![];
+        }
+
+        let value = self.cells[row][col];
+        if value == 0 {
+            return vec![];
+        }
+
+        self.peers(row, col)
+            .into_iter()
+            .filter(|&(peer_row, peer_col)| self.cells[peer_row][peer_col] == value)
+            .collect_vec()
+    }
+
+    /// Finds the empty cell with the fewest candidates, considering only the values placed in its row, column and
+    /// square, with ties broken in row-major order. Returns the row, column and candidates of the cell, or `None` if the
+    /// sudoku is full.
+    pub fn most_constrained_cell(&self) -> Option<(usize, usize, Vec<usize>)> {
+        iproduct!(0..9, 0..9)
+            .filter(|&(row, col)| self.cells[row][col] == 0)
+            .map(|(row, col)| {
+                let peers = self.peers(row, col);
+                let candidates = (1..=9)
+                    .filter(|&value| !peers.iter().any(|&(peer_row, peer_col)| self.cells[peer_row][peer_col] == value))
+                    .collect_vec();
+                (row, col, candidates)
+            })
+            .min_by_key(|(_, _, candidates)| candidates.len())
+    }
+
+    /// Returns `true` if `a` and `b` are the only two cells of a shared row, column or square where `digit` is a
+    /// candidate after easy elimination, i.e. if one of them must hold `digit`, `false` otherwise. Cells are provided
+    /// as `(row, column)` coordinates. Returns `false` if `digit` is not between 1 and 9 or a cell is outside the grid.
+    pub fn is_strong_link(&self, digit: usize, a: (usize, usize), b: (usize, usize)) -> bool {
+        let in_grid = |(row, col): (usize, usize)| row < 9 && col < 9;
+        if !(1..=9).contains(&digit) || !in_grid(a) || !in_grid(b) || !Sudoku::cells_see_each_other(a, b) {
+            return false;
+        }
+
+        let template = reduced_template(self);
+        let units = [Unit::Row(a.0), Unit::Column(a.1), Unit::Square(a.0 / 3, a.1 / 3)];
+        units
+            .iter()
+            .filter(|unit| unit.cells().contains(&b))
+            .any(|unit| {
+                let cells_with_digit = unit.cells()
+                    .into_iter()
+                    .filter(|&(row, col)| {
+                        self.cells[row][col] == 0 && template.cells[row][col].contains_possibility(digit)
+                    })
+                    .collect_vec();
+                cells_with_digit == [a, b] || cells_with_digit == [b, a]
+            })
+    }
+
+    /// Provides how often each digit appears in the filled cells, where the count of digit `d` is at index `d - 1`.
+    pub fn digit_frequencies(&self) -> [usize; 9] {
+        let mut frequencies = [0; 9];
+        for &value in self.cells.iter().flatten() {
+            if (1..=9).contains(&value) {
+                frequencies[value - 1] += 1;
+            }
+        }
+        frequencies
+    }
+
+    /// Returns `true` if rotating the sudoku by 180° and replacing each value `d` with `10 - d` reproduces it, i.e. if
+    /// every clue is paired with its complement in the opposite cell, `false` otherwise. Sudokus with values that are
+    /// not between 0 and 9 are never symmetric.
+    pub fn is_complement_symmetric(&self) -> bool {
+        iproduct!(0..9, 0..9).all(|(row, col)| {
+            let value = self.cells[row][col];
+            let opposite = self.cells[8 - row][8 - col];
+            match value {
+                0 => opposite == 0,
+                1..=9 => opposite == 10 - value,
+                _ => false,
+            }
+        })
+    }
+
+    /// Parses a sudoku from 81 characters in row-major order, where the letters `A` to `I`, in any case, are the values 1
+    /// to 9, and `.` or `0` are empty cells. Any other character is rejected.
+    pub fn from_str_alpha(s: &str) -> Result<Sudoku, SudokuAlphaParsingError> {
+        let values = s.chars()
+            .map(|c| match c.to_ascii_uppercase() {
+                '.' | '0' => Ok(0),
+                letter @ 'A'..='I' => Ok(letter as usize - 'A' as usize + 1),
+                _ => Err(SudokuAlphaParsingError),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if values.len() != 81 {
+            return Err(SudokuAlphaParsingError);
+        }
+
+        let mut cells = [[0; 9]; 9];
+        for (i, value) in values.into_iter().enumerate() {
+            cells[i / 9][i % 9] = value;
+        }
+        Ok(Sudoku::new(cells))
+    }
+
+    /// Provides the sudoku as 81 characters in row-major order, where the values 1 to 9 are the letters `A` to `I` and
+    /// empty cells are dots.
+    pub fn to_string_alpha(&self) -> String {
+        self.cells
+            .iter()
+            .flatten()
+            .map(|&n| if (1..=9).contains(&n) { (b'A' + n as u8 - 1) as char } else { '.' })
+            .collect()
+    }
+
+    /// Provides the sudoku as a minimal JSON document of the form `{"grid":"<81 chars>"}`, where the grid is formatted
+    /// as with `to_string`.
+    pub fn to_json(&self) -> String {
+        format!("{{\"grid\":\"{self}\"}}")
+    }
+
+    /// Parses a sudoku from a JSON document of the form `{"grid":"<81 chars>"}`, as produced by `to_json`. Whitespace
+    /// around the tokens is allowed, but no other members.
+    pub fn from_json(s: &str) -> Result<Sudoku, SudokuJsonParsingError> {
+        let grid = s.trim()
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .and_then(|s| s.trim().strip_prefix("\"grid\""))
+            .and_then(|s| s.trim_start().strip_prefix(':'))
+            .and_then(|s| s.trim().strip_prefix('"'))
+            .and_then(|s| s.strip_suffix('"'))
+            .filter(|grid| !grid.contains(['"', '\n']))
+            .ok_or(SudokuJsonParsingError)?;
+
+        grid.parse().map_err(|_| SudokuJsonParsingError)
+    }
 }
 
 impl From<SudokuTemplate> for Sudoku {
@@ -39,10 +226,35 @@ impl Display for SudokuStrParsingError {
 
 impl Error for SudokuStrParsingError {}
 
+#[derive(Debug)]
+pub struct SudokuAlphaParsingError;
+
+impl Display for SudokuAlphaParsingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Input is not 81 chars of letters A-I, '.' or '0'")
+    }
+}
+
+impl Error for SudokuAlphaParsingError {}
+
+#[derive(Debug)]
+pub struct SudokuJsonParsingError;
+
+impl Display for SudokuJsonParsingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Input is not a {{\"grid\":\"<81 chars>\"}} document")
+    }
+}
+
+impl Error for SudokuJsonParsingError {}
+
 impl FromStr for Sudoku {
     type Err = SudokuStrParsingError;
 
+    /// Parses a sudoku from 81 characters in row-major order, where digits are set cells and any other character is an
+    /// empty cell. A single trailing `\n` or `\r\n` is ignored, so that lines of puzzle files can be parsed directly.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_suffix("\r\n").or_else(|| s.strip_suffix('\n')).unwrap_or(s);
         if s.len() != 81 {
             return Err(SudokuStrParsingError);
         }
@@ -59,9 +271,9 @@ impl FromStr for Sudoku {
     }
 }
 
-impl Sudoku {
-    pub fn to_string(&self) -> String {
-        self.cells
+impl Display for Sudoku {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s: String = self.cells
             .iter()
             .flatten()
             .map(|&n| {
@@ -71,6 +283,363 @@ impl Sudoku {
                     char::from_digit(n as u32, 10).unwrap_or('.')
                 }
             })
-            .collect()
+            .collect();
+        write!(f, "{s}")
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use itertools::Itertools;
+
+    use crate::Sudoku;
+
+    const SUDOKU: &str = "\
+        1........\
+        ...1.....\
+        ......1..\
+        .1.......\
+        ....1....\
+        .......1.\
+        ..1......\
+        .....1...\
+        ........2\
+    ";
+
+    #[test]
+    fn positions_of_returns_cells_containing_digit() {
+        // Given a sudoku with a known placement pattern of the digit 1.
+        let sudoku = SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I get the positions of the digit 1, then all its cells should be returned in row-major order.
+        assert_eq!(
+            sudoku.positions_of(1),
+            vec![(0, 0), (1, 3), (2, 6), (3, 1), (4, 4), (5, 7), (6, 2), (7, 5)],
+        );
+        assert_eq!(sudoku.positions_of(2), vec![(8, 8)]);
+        assert_eq!(sudoku.positions_of(3), vec![]);
+    }
+
+    #[test]
+    fn positions_of_returns_nothing_for_invalid_digit() {
+        // Given a sudoku with empty cells.
+        let sudoku = SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I get the positions of invalid digits, then nothing should be returned.
+        assert_eq!(sudoku.positions_of(0), vec![]);
+        assert_eq!(sudoku.positions_of(10), vec![]);
+    }
+
+    #[test]
+    fn cells_see_each_other_for_related_cells() {
+        // Cells in the same row, column or square see each other.
+        assert_eq!(Sudoku::cells_see_each_other((0, 0), (0, 8)), true);
+        assert_eq!(Sudoku::cells_see_each_other((0, 4), (8, 4)), true);
+        assert_eq!(Sudoku::cells_see_each_other((3, 3), (5, 5)), true);
+    }
+
+    #[test]
+    fn cells_see_each_other_for_unrelated_cells() {
+        // Cells in different rows, columns and squares, or the same cell, do not see each other.
+        assert_eq!(Sudoku::cells_see_each_other((0, 0), (3, 3)), false);
+        assert_eq!(Sudoku::cells_see_each_other((2, 2), (3, 3)), false);
+        assert_eq!(Sudoku::cells_see_each_other((4, 4), (4, 4)), false);
+    }
+
+    #[test]
+    fn is_complement_symmetric_detects_complement_pairs() {
+        // Given a sudoku where each clue is paired with its complement in the opposite cell.
+        let sudoku = "\
+            1.......8\
+            ..3......\
+            .........\
+            ....2....\
+            ....5....\
+            ....8....\
+            .........\
+            ......7..\
+            2.......9\
+        ".parse::<Sudoku>().unwrap();
+
+        // When I check its complement symmetry, then it should be symmetric.
+        assert_eq!(sudoku.is_complement_symmetric(), true);
+    }
+
+    #[test]
+    fn is_complement_symmetric_rejects_plain_symmetry() {
+        // Given a sudoku whose clue pattern is symmetric, but with equal values in opposite cells.
+        let sudoku = SUDOKU.parse::<Sudoku>().unwrap();
+        let plain = "\
+            1........\
+            .........\
+            .........\
+            .........\
+            .........\
+            .........\
+            .........\
+            .........\
+            ........1\
+        ".parse::<Sudoku>().unwrap();
+
+        // When I check their complement symmetry, then they should not be symmetric.
+        assert_eq!(sudoku.is_complement_symmetric(), false);
+        assert_eq!(plain.is_complement_symmetric(), false);
+    }
+
+    #[test]
+    fn is_complement_symmetric_rejects_invalid_values() {
+        // Given a sudoku with a value above 9, whose complement cannot be computed.
+        let mut cells = [[0; 9]; 9];
+        cells[0][0] = 11;
+        let sudoku = Sudoku::new(cells);
+
+        // When I check its complement symmetry, then it should not be symmetric.
+        assert_eq!(sudoku.is_complement_symmetric(), false);
+    }
+
+    #[test]
+    fn placement_conflicts_returns_peers_with_same_value() {
+        // Given a sudoku where the first cell holds a 1 that is repeated later in its row.
+        let sudoku = SUDOKU.replacen("1........", "1.......1", 1).parse::<Sudoku>().unwrap();
+
+        // When I get the conflicts of the first cell, then the repeated value should be returned.
+        assert_eq!(sudoku.placement_conflicts(0, 0), vec![(0, 8)]);
+    }
+
+    #[test]
+    fn placement_conflicts_returns_nothing_without_conflict() {
+        // Given a sudoku without conflicts.
+        let sudoku = SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I get the conflicts of a filled cell and of an empty cell, then nothing should be returned.
+        assert_eq!(sudoku.placement_conflicts(0, 0), vec![]);
+        assert_eq!(sudoku.placement_conflicts(0, 1), vec![]);
+    }
+
+    #[test]
+    fn placement_conflicts_returns_nothing_outside_grid() {
+        // Given a sudoku.
+        let sudoku = SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I get the conflicts of cells outside the grid, then nothing should be returned.
+        assert_eq!(sudoku.placement_conflicts(9, 9), vec![]);
+        assert_eq!(sudoku.placement_conflicts(0, 9), vec![]);
+    }
+
+    #[test]
+    fn to_json_and_from_json_round_trip() {
+        // Given a sudoku.
+        let sudoku = SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I export it to JSON and parse it back.
+        let json = sudoku.to_json();
+        let parsed = Sudoku::from_json(&json).unwrap();
+
+        // Then the document should contain the grid, and the parsed sudoku should be equal to the original.
+        assert_eq!(json, format!("{{\"grid\":\"{SUDOKU}\"}}"));
+        assert_eq!(parsed.get_cells(), sudoku.get_cells());
+    }
+
+    #[test]
+    fn from_json_allows_whitespace() {
+        // Given a JSON document with whitespace around its tokens.
+        let json = format!(" {{ \"grid\" : \"{SUDOKU}\" }}\n");
+
+        // When I parse it, then the sudoku should be parsed.
+        assert_eq!(Sudoku::from_json(&json).unwrap().to_string(), SUDOKU);
+    }
+
+    #[test]
+    fn from_json_rejects_malformed_documents() {
+        // Given malformed JSON documents.
+        let documents = [
+            format!("{{\"grid\":\"{SUDOKU}\""),
+            format!("{{\"cells\":\"{SUDOKU}\"}}"),
+            format!("{{\"grid\":{SUDOKU}}}"),
+            format!("{{\"grid\":\"{}\"}}", &SUDOKU[1..]),
+            format!("{{\"grid\":\"{SUDOKU}\",\"name\":\"x\"}}"),
+        ];
+
+        // When I parse them, then they should be rejected.
+        for document in documents {
+            assert_eq!(Sudoku::from_json(&document).is_err(), true, "{document} was accepted.");
+        }
+    }
+
+    #[test]
+    fn most_constrained_cell_returns_cell_with_fewest_candidates() {
+        // Given a sudoku where only the cell (4, 2) has a single candidate, 5.
+        let sudoku = "\
+            ..1......\
+            ..2......\
+            ..3......\
+            4.6......\
+            ...7.8.9.\
+            .........\
+            .........\
+            .........\
+            .........\
+        ".parse::<Sudoku>().unwrap();
+
+        // When I get the most constrained cell, then it should be returned with its only candidate.
+        assert_eq!(sudoku.most_constrained_cell(), Some((4, 2, vec![5])));
+    }
+
+    #[test]
+    fn most_constrained_cell_breaks_ties_in_row_major_order() {
+        // Given an empty sudoku and a full one.
+        let empty = ".".repeat(81).parse::<Sudoku>().unwrap();
+        let full = "123456789".repeat(9).parse::<Sudoku>().unwrap();
+
+        // When I get their most constrained cell, then the first cell should be returned for the empty sudoku, and
+        // nothing for the full one.
+        assert_eq!(empty.most_constrained_cell(), Some((0, 0, (1..=9).collect())));
+        assert_eq!(full.most_constrained_cell(), None);
+    }
+
+    #[test]
+    fn standard_regions_maps_cells_to_their_square() {
+        // When I get the standard regions.
+        let regions = Sudoku::standard_regions();
+
+        // Then each cell should map to its square, with the center cell in the center square.
+        assert_eq!(regions[4][4], 4);
+        assert_eq!(regions[0], [0, 0, 0, 1, 1, 1, 2, 2, 2]);
+        assert_eq!(regions[8][0], 6);
+    }
+
+    #[test]
+    fn from_str_alpha_and_to_string_alpha_round_trip() {
+        // Given a sudoku, and its alphabetic representation using both cases and both empty cell markers.
+        let sudoku = SUDOKU.parse::<Sudoku>().unwrap();
+        let alpha = SUDOKU.replace('1', "A").replace('2', "b").replacen('.', "0", 3);
+
+        // When I parse the alphabetic representation and format it back.
+        let parsed = Sudoku::from_str_alpha(&alpha).unwrap();
+
+        // Then it should be equal to the sudoku, and formatted with uppercase letters and dots.
+        assert_eq!(parsed.get_cells(), sudoku.get_cells());
+        assert_eq!(parsed.to_string_alpha(), SUDOKU.replace('1', "A").replace('2', "B"));
+        assert_eq!(Sudoku::from_str_alpha(&parsed.to_string_alpha()).unwrap().get_cells(), sudoku.get_cells());
+    }
+
+    #[test]
+    fn from_str_alpha_rejects_invalid_input() {
+        // Given alphabetic representations with a letter beyond `I`, a digit other than 0, and a wrong length.
+        let beyond_i = SUDOKU.replace('1', "A").replacen('.', "J", 1);
+        let with_digit = SUDOKU.replace('1', "A").replacen('.', "5", 1);
+        let too_short = &SUDOKU.replace('1', "A")[1..];
+
+        // When I parse them, then they should be rejected.
+        assert_eq!(Sudoku::from_str_alpha(&beyond_i).is_err(), true);
+        assert_eq!(Sudoku::from_str_alpha(&with_digit).is_err(), true);
+        assert_eq!(Sudoku::from_str_alpha(too_short).is_err(), true);
+    }
+
+    #[test]
+    fn parsing_errors_describe_expected_input() {
+        // When I fail to parse a sudoku from digits and from letters, then each error should describe its format.
+        assert_eq!("123".parse::<Sudoku>().unwrap_err().to_string(), "Input is not 81 chars long");
+        assert_eq!(
+            Sudoku::from_str_alpha("ABC").unwrap_err().to_string(),
+            "Input is not 81 chars of letters A-I, '.' or '0'",
+        );
+    }
+
+    const SUDOKU_WITH_CONJUGATE_PAIR: &str = "\
+        .234.6.89\
+        .........\
+        .........\
+        .........\
+        ......1..\
+        .........\
+        .........\
+        .........\
+        .........\
+    ";
+
+    #[test]
+    fn is_strong_link_detects_conjugate_pair() {
+        // Given a sudoku where 1 is only a candidate of the cells (0, 0) and (0, 4) in the first row.
+        let sudoku = SUDOKU_WITH_CONJUGATE_PAIR.parse::<Sudoku>().unwrap();
+
+        // When I check if these cells form a strong link on 1, then they should, in any order.
+        assert_eq!(sudoku.is_strong_link(1, (0, 0), (0, 4)), true);
+        assert_eq!(sudoku.is_strong_link(1, (0, 4), (0, 0)), true);
+    }
+
+    #[test]
+    fn is_strong_link_rejects_non_pairs() {
+        // Given a sudoku where 5 is a candidate of three cells in the first row.
+        let sudoku = SUDOKU_WITH_CONJUGATE_PAIR.parse::<Sudoku>().unwrap();
+
+        // When I check if two of them form a strong link, then they should not, and neither should cells on other
+        // digits, cells that don't share a unit, or a cell with itself.
+        assert_eq!(sudoku.is_strong_link(5, (0, 0), (0, 4)), false);
+        assert_eq!(sudoku.is_strong_link(1, (0, 0), (0, 6)), false);
+        assert_eq!(sudoku.is_strong_link(1, (0, 0), (4, 4)), false);
+        assert_eq!(sudoku.is_strong_link(1, (0, 0), (0, 0)), false);
+        assert_eq!(sudoku.is_strong_link(0, (0, 0), (0, 4)), false);
+    }
+
+    #[test]
+    fn is_strong_link_rejects_cells_outside_grid() {
+        // Given a sudoku.
+        let sudoku = SUDOKU_WITH_CONJUGATE_PAIR.parse::<Sudoku>().unwrap();
+
+        // When I check cells outside the grid, then they should not form a strong link.
+        assert_eq!(sudoku.is_strong_link(1, (9, 9), (9, 8)), false);
+        assert_eq!(sudoku.is_strong_link(1, (0, 0), (0, 9)), false);
+    }
+
+    #[test]
+    fn peers_returns_20_unique_cells() {
+        // Given a sudoku.
+        let sudoku = SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I get the peers of a corner cell and of the center cell.
+        for (row, col) in [(0, 0), (4, 4)] {
+            let peers = sudoku.peers(row, col);
+
+            // Then there should be 20 unique peers, all seeing the cell, and not including the cell itself.
+            assert_eq!(peers.len(), 20);
+            assert_eq!(peers.iter().unique().count(), 20);
+            assert_eq!(peers.contains(&(row, col)), false);
+            assert_eq!(peers.iter().all(|&peer| Sudoku::cells_see_each_other((row, col), peer)), true);
+        }
+    }
+
+    #[test]
+    fn digit_frequencies_counts_filled_cells() {
+        // Given a sudoku with eight clues of 1, a single clue of 2 and no other clues.
+        let sudoku = SUDOKU.parse::<Sudoku>().unwrap();
+
+        // When I get the digit frequencies, then they should count each digit.
+        assert_eq!(sudoku.digit_frequencies(), [8, 1, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn from_str_ignores_single_trailing_newline() {
+        // Given a sudoku line ending with a newline, and one ending with a carriage return and a newline.
+        let unix_line = format!("{SUDOKU}\n");
+        let windows_line = format!("{SUDOKU}\r\n");
+
+        // When I parse them, then they should be parsed as the sudoku without the line ending.
+        assert_eq!(unix_line.parse::<Sudoku>().unwrap().to_string(), SUDOKU);
+        assert_eq!(windows_line.parse::<Sudoku>().unwrap().to_string(), SUDOKU);
+    }
+
+    #[test]
+    fn from_str_rejects_wrong_length_before_newline() {
+        // Given lines that are too short or too long before their line ending.
+        let short_line = format!("{}\n", &SUDOKU[1..]);
+        let long_line = format!("{SUDOKU}.\n");
+        let double_newline = format!("{SUDOKU}\n\n");
+
+        // When I parse them, then they should be rejected.
+        assert_eq!(short_line.parse::<Sudoku>().is_err(), true);
+        assert_eq!(long_line.parse::<Sudoku>().is_err(), true);
+        assert_eq!(double_newline.parse::<Sudoku>().is_err(), true);
     }
 }
@@ -1,7 +1,11 @@
-use itertools::{iproduct, Itertools};
+use itertools::Itertools;
 
 use crate::traits::cell::Cell;
 use crate::traits::sudoku::Sudoku;
+use crate::traits::unit::Unit;
+
+/// Bitmask with the bits `1..=9` set, each bit representing the digit with the same value.
+pub(crate) const ALL_DIGITS: u16 = 0b11_1111_1110;
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub(crate) struct SudokuTemplate {
@@ -9,6 +13,12 @@ pub(crate) struct SudokuTemplate {
 }
 
 impl SudokuTemplate {
+    /// Provides the coordinates of the cells of a square, identified by its row and column among the squares, in
+    /// row-major order.
+    pub(crate) fn square_cells(sq_row: usize, sq_col: usize) -> [(usize, usize); 9] {
+        Unit::Square(sq_row, sq_col).cells()
+    }
+
     pub(crate) fn get_values_in_row(&self, row: usize) -> Vec<usize> {
         self.cells[row]
             .iter()
@@ -26,8 +36,9 @@ impl SudokuTemplate {
     }
 
     pub(crate) fn get_values_in_square(&self, row: usize, column: usize) -> Vec<usize> {
-        iproduct!((0..3), (0..3))
-            .map(|(x, y)| &self.cells[3 * row + x][3 * column + y])
+        SudokuTemplate::square_cells(row, column)
+            .iter()
+            .map(|&(x, y)| &self.cells[x][y])
             .filter(|&cell| cell.is_set())
             .map(|cell| cell.get_value())
             .collect_vec()
@@ -48,14 +59,141 @@ impl SudokuTemplate {
     pub(crate) fn get_missing_values_in_square(&self, row: usize, column: usize) -> Vec<usize> {
         SudokuTemplate::get_missing_values(&self.get_values_in_square(row, column))
     }
+
+    /// Provides the values that are not set in any of the provided cells as a bitmask, where the bit `n` is set if the
+    /// value `n` is missing.
+    fn get_missing_mask<'a>(cells: impl Iterator<Item=&'a Cell>) -> u16 {
+        let present = cells
+            .filter(|cell| cell.is_set())
+            .fold(0u16, |mask, cell| mask | 1 << cell.get_value());
+        ALL_DIGITS & !present
+    }
+
+    /// Same as `get_missing_values_in_row`, but as a bitmask where the bit `n` is set if the value `n` is missing.
+    pub(crate) fn missing_mask_row(&self, row: usize) -> u16 {
+        SudokuTemplate::get_missing_mask(self.cells[row].iter())
+    }
+
+    /// Same as `get_missing_values_in_column`, but as a bitmask where the bit `n` is set if the value `n` is missing.
+    pub(crate) fn missing_mask_column(&self, column: usize) -> u16 {
+        SudokuTemplate::get_missing_mask((0..9).map(|row| &self.cells[row][column]))
+    }
+
+    /// Same as `get_missing_values_in_square`, but as a bitmask where the bit `n` is set if the value `n` is missing.
+    pub(crate) fn missing_mask_square(&self, row: usize, column: usize) -> u16 {
+        let cells = SudokuTemplate::square_cells(row, column);
+        SudokuTemplate::get_missing_mask(cells.iter().map(|&(x, y)| &self.cells[x][y]))
+    }
+
+    /// Provides the values whose bits are set in a mask, in increasing order.
+    pub(crate) fn mask_values(mask: u16) -> impl Iterator<Item=usize> + Clone {
+        (1..=9).filter(move |value| mask & 1 << value != 0)
+    }
+
+    /// Provides the masks made of `len` of the bits set in a mask, i.e. the combinations of its values, without
+    /// allocating them.
+    pub(crate) fn mask_combinations(mask: u16, len: usize) -> impl Iterator<Item=u16> {
+        std::iter::successors(Some(mask), move |&subset| (subset != 0).then(|| (subset - 1) & mask))
+            .filter(move |subset| subset.count_ones() as usize == len)
+    }
 }
 
 impl From<Sudoku> for SudokuTemplate {
     fn from(sudoku: Sudoku) -> SudokuTemplate {
-        let cells: [[Cell; 9]; 9] = sudoku.get_cells().map(|row| row.map(|n| Cell::new(n)));
+        let cells: [[Cell; 9]; 9] = sudoku.get_cells().map(|row| row.map(Cell::new));
+
+        SudokuTemplate {
+            cells
+        }
+    }
+}
+
+impl From<&[[Vec<usize>; 9]; 9]> for SudokuTemplate {
+    fn from(candidates: &[[Vec<usize>; 9]; 9]) -> SudokuTemplate {
+        let cells = candidates.each_ref().map(|row| row.each_ref().map(|c| Cell::from_possibilities(c)));
 
         SudokuTemplate {
             cells
         }
     }
-}
\ No newline at end of file
+}
+
+impl From<&SudokuTemplate> for [[Vec<usize>; 9]; 9] {
+    fn from(sudoku: &SudokuTemplate) -> [[Vec<usize>; 9]; 9] {
+        sudoku.cells.each_ref().map(|row| row.each_ref().map(|cell| cell.possible_values()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::{iproduct, Itertools};
+
+    use crate::traits::SudokuTemplate;
+    use crate::Sudoku;
+
+    const SUDOKU: &str = "\
+        ...6.94..\
+        29..8....\
+        .6...5...\
+        .........\
+        5......72\
+        912467583\
+        3..17..9.\
+        159..2...\
+        ...9...1.\
+    ";
+
+    #[test]
+    fn missing_masks_match_missing_values() {
+        // Given a sudoku with some filled cells.
+        let sudoku = SudokuTemplate::from(SUDOKU.parse::<Sudoku>().unwrap());
+
+        // When I get the missing values of each unit as masks, then they should match the missing values.
+        for i in 0..9 {
+            assert_eq!(
+                SudokuTemplate::mask_values(sudoku.missing_mask_row(i)).collect_vec(),
+                sudoku.get_missing_values_in_row(i),
+            );
+            assert_eq!(
+                SudokuTemplate::mask_values(sudoku.missing_mask_column(i)).collect_vec(),
+                sudoku.get_missing_values_in_column(i),
+            );
+        }
+        for (row, column) in iproduct!(0..3, 0..3) {
+            assert_eq!(
+                SudokuTemplate::mask_values(sudoku.missing_mask_square(row, column)).collect_vec(),
+                sudoku.get_missing_values_in_square(row, column),
+            );
+        }
+    }
+
+    #[test]
+    fn missing_masks_of_full_and_empty_units() {
+        // Given a sudoku with a full row 5 and an empty row 3.
+        let sudoku = SudokuTemplate::from(SUDOKU.parse::<Sudoku>().unwrap());
+
+        // When I get their masks, then no values should be missing from the full row and all from the empty row.
+        assert_eq!(sudoku.missing_mask_row(5), 0);
+        assert_eq!(SudokuTemplate::mask_values(sudoku.missing_mask_row(3)).collect_vec(), (1..=9).collect_vec());
+    }
+
+    #[test]
+    fn mask_combinations_provides_subsets_of_given_size() {
+        // Given a mask of the values 2, 5 and 7.
+        let mask = 1 << 2 | 1 << 5 | 1 << 7;
+
+        // When I get its combinations of two values, then each pair should be provided once.
+        let mut combinations = SudokuTemplate::mask_combinations(mask, 2).collect_vec();
+        combinations.sort();
+        assert_eq!(combinations, vec![1 << 2 | 1 << 5, 1 << 2 | 1 << 7, 1 << 5 | 1 << 7]);
+    }
+
+    #[test]
+    fn square_cells_returns_cells_of_square() {
+        // When I get the cells of the center square, then they should be provided in row-major order.
+        assert_eq!(
+            SudokuTemplate::square_cells(1, 1),
+            [(3, 3), (3, 4), (3, 5), (4, 3), (4, 4), (4, 5), (5, 3), (5, 4), (5, 5)],
+        );
+    }
+}
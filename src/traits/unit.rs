@@ -0,0 +1,35 @@
+use itertools::iproduct;
+
+/// Represents a unit of a sudoku, i.e. a group of nine cells that must contain each value exactly once.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Unit {
+    /// A row, identified by its index.
+    Row(usize),
+    /// A column, identified by its index.
+    Column(usize),
+    /// A square, identified by its row and column among the squares, e.g. `Square(1, 1)` is the center square.
+    Square(usize, usize),
+}
+
+impl Unit {
+    /// Returns `true` if the indexes of the unit are within the sudoku, `false` otherwise.
+    pub fn is_valid(&self) -> bool {
+        match *self {
+            Unit::Row(row) => row < 9,
+            Unit::Column(col) => col < 9,
+            Unit::Square(sq_row, sq_col) => sq_row < 3 && sq_col < 3,
+        }
+    }
+
+    /// Provides the coordinates of the cells of the unit, in row-major order.
+    pub fn cells(&self) -> [(usize, usize); 9] {
+        let mut cells = [(0, 0); 9];
+        match *self {
+            Unit::Row(row) => (0..9).for_each(|col| cells[col] = (row, col)),
+            Unit::Column(col) => (0..9).for_each(|row| cells[row] = (row, col)),
+            Unit::Square(sq_row, sq_col) => iproduct!(0..3, 0..3)
+                .for_each(|(x, y)| cells[3 * x + y] = (3 * sq_row + x, 3 * sq_col + y)),
+        }
+        cells
+    }
+}
@@ -9,6 +9,6 @@ impl<I: Iterator<Item=bool>> BoolIteratorUtils for I {
     }
 
     fn any_true_exhaustive(self) -> bool {
-        self.fold(false, |acc, b| acc || b)
+        self.fold(false, |acc, b| acc | b)
     }
 }
@@ -2,10 +2,11 @@ use std::ops::Not;
 
 use crate::traits::Sudoku;
 
+#[allow(dead_code)]
 pub(crate) fn is_valid(sudoku: &Sudoku) -> bool {
     sudoku.get_cells()
-        .into_iter()
+        .iter()
         .flatten()
-        .any(|x| *x < 0 || *x > 9)
+        .any(|x| *x > 9)
         .not()
 }
@@ -1,7 +1,10 @@
 mod examples;
 
 use crate::examples::{EASY_SUDOKUS, MEDIUM_SUDOKUS};
-use sudoku_utils::{solve, Sudoku};
+use sudoku_utils::{
+    solve, solve_assuming_reduced, solve_by_tiers, solve_from_candidates, solve_latin_square, solve_op_count,
+    solve_step_count, solve_with_custom, solve_with_exclusions, validate_candidates, Difficulty, PublicStrategy, Sudoku,
+};
 
 fn assert_solved_correctly(sudoku: &str, solution: &str) {
     assert_eq!(
@@ -17,3 +20,239 @@ fn solve_fn_correctly_solves_sudokus() {
         .chain(MEDIUM_SUDOKUS.iter())
         .for_each(|[sudoku, solution]| assert_solved_correctly(sudoku, solution))
 }
+
+#[test]
+fn solve_latin_square_fn_ignores_squares() {
+    // Given a cyclic Latin square, which repeats values inside its squares, with some cells removed.
+    let latin_square = "\
+        123456789\
+        234567891\
+        345678912\
+        456789123\
+        567891234\
+        678912345\
+        789123456\
+        891234567\
+        912345678\
+    ";
+    let grid = "\
+        .23.5....\
+        23.5678..\
+        345678..2\
+        4567891.3\
+        ..7891.34\
+        .7.912.4.\
+        7.9.2.456\
+        8912.4.67\
+        9.23..678\
+    ".parse::<Sudoku>().unwrap();
+    let correct_cells = |sudoku: &Sudoku| sudoku.to_string()
+        .chars()
+        .zip(latin_square.chars())
+        .filter(|(a, b)| a == b)
+        .count();
+
+    // When I solve it as a Latin square and as a sudoku.
+    let latin_square_solution = solve_latin_square(&grid);
+    let sudoku_solution = solve(&grid);
+
+    // Then the Latin square mode should complete it, getting further than the sudoku mode.
+    assert_eq!(latin_square_solution.to_string(), latin_square);
+    assert!(correct_cells(&sudoku_solution) < correct_cells(&latin_square_solution));
+}
+
+#[test]
+fn solve_from_candidates_fn_uses_provided_candidates() {
+    // Given candidates for an empty grid, where 7 is not a candidate in the first row except in its first cell.
+    let mut candidates: [[Vec<usize>; 9]; 9] = std::array::from_fn(|_| std::array::from_fn(|_| (1..=9).collect()));
+    (1..9).for_each(|col| candidates[0][col].retain(|&n| n != 7));
+    let empty_grid = ".".repeat(81).parse::<Sudoku>().unwrap();
+
+    // When I solve from the candidates, then 7 should be placed in the first cell, unlike when solving the grid.
+    assert_eq!(solve_from_candidates(&candidates).get_cells()[0][0], 7);
+    assert_eq!(solve(&empty_grid).get_cells()[0][0], 0);
+}
+
+#[test]
+fn solve_from_candidates_fn_sets_single_candidates() {
+    // Given the candidates of a sudoku, where each given is the only candidate of its cell.
+    let [sudoku, solution] = EASY_SUDOKUS[0];
+    let candidates = sudoku.parse::<Sudoku>().unwrap().get_cells().map(|row| row.map(|n| {
+        if n == 0 { (1..=9).collect() } else { vec![n] }
+    }));
+
+    // When I solve from the candidates, then the sudoku should be solved.
+    assert_eq!(solve_from_candidates(&candidates).to_string(), solution);
+}
+
+#[test]
+fn solve_from_candidates_fn_solves_validated_candidates() {
+    // Given the candidates of a sudoku, where each given is the only candidate of its cell, and the values placed in
+    // their peers are removed from the candidates of the empty cells.
+    let [sudoku, solution] = EASY_SUDOKUS[0];
+    let sudoku = sudoku.parse::<Sudoku>().unwrap();
+    let cells = sudoku.get_cells();
+    let candidates: [[Vec<usize>; 9]; 9] = std::array::from_fn(|row| std::array::from_fn(|col| {
+        if cells[row][col] != 0 {
+            return vec![cells[row][col]];
+        }
+        let peers = sudoku.peers(row, col);
+        (1..=9).filter(|&value| peers.iter().all(|&(r, c)| cells[r][c] != value)).collect()
+    }));
+
+    // When I validate the candidates and solve from them, then they should be accepted and the sudoku solved.
+    assert_eq!(validate_candidates(&sudoku, &candidates), Ok(()));
+    assert_eq!(solve_from_candidates(&candidates).to_string(), solution);
+}
+
+#[test]
+fn solve_with_exclusions_fn_fails_when_excluding_the_solution() {
+    // Given a sudoku whose first cell is empty.
+    let [sudoku, solution] = EASY_SUDOKUS[0];
+    let sudoku = sudoku.parse::<Sudoku>().unwrap();
+    assert_eq!(sudoku.get_cells()[0][0], 0);
+    let value = solution.chars().next().unwrap().to_digit(10).unwrap() as usize;
+
+    // When I solve it while excluding the correct value of the first cell.
+    let result = solve_with_exclusions(&sudoku, &[(0, 0, value)]);
+
+    // Then it should not be solved.
+    assert_ne!(result.to_string(), solution);
+    assert_ne!(result.get_cells()[0][0], value);
+}
+
+#[test]
+fn solve_with_exclusions_fn_solves_when_excluding_a_wrong_value() {
+    // Given a sudoku whose first cell is empty.
+    let [sudoku, solution] = EASY_SUDOKUS[0];
+    let sudoku = sudoku.parse::<Sudoku>().unwrap();
+    let value = solution.chars().next().unwrap().to_digit(10).unwrap() as usize;
+
+    // When I solve it while excluding a wrong value of the first cell, then it should still be solved.
+    let wrong_value = value % 9 + 1;
+    assert_eq!(solve_with_exclusions(&sudoku, &[(0, 0, wrong_value)]).to_string(), solution);
+}
+
+#[test]
+fn solve_with_exclusions_fn_ignores_exclusions_outside_grid() {
+    // Given a sudoku and exclusions outside the grid or with invalid values.
+    let [sudoku, solution] = EASY_SUDOKUS[0];
+    let sudoku = sudoku.parse::<Sudoku>().unwrap();
+    let exclusions = [(9, 0, 1), (0, 9, 1), (9, 9, 1), (0, 0, 10)];
+
+    // When I solve it with these exclusions, then they should be ignored and the sudoku solved.
+    assert_eq!(solve_with_exclusions(&sudoku, &exclusions).to_string(), solution);
+}
+
+struct FillFirstCellWithSeven;
+
+impl PublicStrategy for FillFirstCellWithSeven {
+    fn solve(&self, candidates: &mut [[Vec<usize>; 9]; 9]) -> bool {
+        if candidates[0][0] == vec![7] {
+            return false;
+        }
+        candidates[0][0] = vec![7];
+        true
+    }
+
+    fn name(&self) -> &'static str {
+        "Fill first cell with seven"
+    }
+}
+
+#[test]
+fn solve_with_custom_fn_applies_custom_strategies() {
+    // Given an empty sudoku, on which the implemented strategies cannot make any progress.
+    let sudoku = ".".repeat(81).parse::<Sudoku>().unwrap();
+
+    // When I solve it with a custom strategy that fills the first cell.
+    let result = solve_with_custom(&sudoku, vec![Box::new(FillFirstCellWithSeven)]);
+
+    // Then the first cell should be filled, and nothing else.
+    assert_eq!(result.get_cells()[0][0], 7);
+    assert_eq!(result.to_string(), "7".to_string() + &".".repeat(80));
+}
+
+#[test]
+fn solve_by_tiers_fn_reveals_progress_by_difficulty() {
+    // Given a sudoku that cannot be solved using only easy strategies.
+    let [sudoku, solution] = MEDIUM_SUDOKUS[0];
+    let sudoku = sudoku.parse::<Sudoku>().unwrap();
+    let filled_cells = |sudoku: &Sudoku| sudoku.get_cells().iter().flatten().filter(|&&value| value != 0).count();
+
+    // When I solve it tier by tier.
+    let tiers = solve_by_tiers(&sudoku);
+
+    // Then each tier should be returned in order, and the hard tier should get further than the easy tier.
+    let difficulties = tiers.iter().map(|(difficulty, _)| *difficulty).collect::<Vec<_>>();
+    assert_eq!(difficulties, vec![Difficulty::Easy, Difficulty::Medium, Difficulty::Hard]);
+    assert!(filled_cells(&tiers[0].1) < filled_cells(&tiers[2].1));
+    assert_eq!(tiers[2].1.to_string(), solution);
+}
+
+#[test]
+fn solve_assuming_reduced_fn_matches_solve() {
+    for [sudoku, _] in EASY_SUDOKUS.iter().chain(MEDIUM_SUDOKUS.iter()) {
+        // Given a sudoku and its candidates, reduced by removing the only candidate of each cell from its peers.
+        let sudoku = sudoku.parse::<Sudoku>().unwrap();
+        let cells = sudoku.get_cells();
+        let mut candidates: [[Vec<usize>; 9]; 9] = std::array::from_fn(|row| std::array::from_fn(|col| {
+            if cells[row][col] != 0 { vec![cells[row][col]] } else { (1..=9).collect() }
+        }));
+        let mut reduced = false;
+        while !reduced {
+            reduced = true;
+            for (row, col) in (0..81).map(|i| (i / 9, i % 9)) {
+                if let [value] = candidates[row][col][..] {
+                    for (peer_row, peer_col) in sudoku.peers(row, col) {
+                        let peer_candidates = &mut candidates[peer_row][peer_col];
+                        if peer_candidates.len() > 1 && peer_candidates.contains(&value) {
+                            peer_candidates.retain(|&candidate| candidate != value);
+                            reduced = false;
+                        }
+                    }
+                }
+            }
+        }
+
+        // When I solve it from the reduced candidates, then the result should be the same as with the solve fn.
+        assert_eq!(solve_assuming_reduced(&candidates).to_string(), solve(&sudoku).to_string());
+    }
+}
+
+#[test]
+fn solve_step_count_fn_is_higher_for_longer_puzzles() {
+    // Given a solved sudoku with a single empty cell, and a puzzle with the same solution.
+    let [puzzle, solution] = EASY_SUDOKUS[0];
+    let short = (".".to_string() + &solution[1..]).parse::<Sudoku>().unwrap();
+    let long = puzzle.parse::<Sudoku>().unwrap();
+
+    // When I count their steps, then the puzzle should take more steps.
+    let short_steps = solve_step_count(&short).unwrap();
+    let long_steps = solve_step_count(&long).unwrap();
+    assert!(short_steps < long_steps, "{short_steps} is not lower than {long_steps}.");
+}
+
+#[test]
+fn solve_step_count_fn_returns_none_for_unsolvable_sudoku() {
+    // Given an empty sudoku, which cannot be solved logically.
+    let sudoku = ".".repeat(81).parse::<Sudoku>().unwrap();
+
+    // When I count its steps, then nothing should be returned.
+    assert_eq!(solve_step_count(&sudoku), None);
+}
+
+#[test]
+fn solve_op_count_fn_pins_operations_for_fixture() {
+    // Given a fixture sudoku.
+    let [sudoku, solution] = MEDIUM_SUDOKUS[0];
+    let sudoku = sudoku.parse::<Sudoku>().unwrap();
+
+    // When I solve it while counting operations.
+    let (solved, op_count) = solve_op_count(&sudoku);
+
+    // Then it should be solved, with the same number of operations every time.
+    assert_eq!(solved.to_string(), solution);
+    assert_eq!(op_count, 33183);
+    assert_eq!(solve_op_count(&sudoku).1, op_count);
+}